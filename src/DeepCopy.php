@@ -0,0 +1,123 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * Copies values so that later mutations of the original do not affect the copy.
+ *
+ * @internal
+ */
+final class DeepCopy
+{
+    /**
+     * The value copied in place of an array that contains itself.
+     */
+    private const string RECURSION_MARKER = '*RECURSION*';
+
+    /**
+     * Prevents instantiation since this is a static helper.
+     *
+     * @codeCoverageIgnore
+     */
+    private function __construct()
+    {
+    }
+
+    /**
+     * Returns a deep copy of the value.
+     *
+     * Arrays are copied element by element and objects property by property, recursively. Each
+     * object is copied once, so an object reachable through several paths (or through a cycle)
+     * is shared by those paths in the copy just as in the original. Objects of built-in classes
+     * are copied with `clone` before their properties are copied; objects that cannot be cloned
+     * (enum cases, generators, classes with a private __clone(), ...) are shared. An array reached
+     * again through a reference to one of its ancestors is replaced by "*RECURSION*". Scalars,
+     * null and resources are returned as-is.
+     *
+     * @template V
+     *
+     * @param V $value
+     *
+     * @return V
+     */
+    public static function of(mixed $value): mixed
+    {
+        /** @var \WeakMap<object, object> $copies */
+        $copies = new \WeakMap();
+
+        /** @var V */
+        return self::copy($value, $copies);
+    }
+
+    /**
+     * Copies a value, reusing the copies of objects that were already copied.
+     *
+     * @param \WeakMap<object, object> $copies maps each original object to its copy
+     * @param array<string, true> $path the reference ids of the arrays on the current path
+     */
+    private static function copy(mixed $value, \WeakMap $copies, array $path = []): mixed
+    {
+        if (\is_array($value)) {
+            $copy = [];
+            foreach ($value as $key => $element) {
+                $id = \ReflectionReference::fromArrayElement($value, $key)?->getId();
+                if ($id !== null && isset($path[$id])) {
+                    $copy[$key] = self::RECURSION_MARKER;
+
+                    continue;
+                }
+                $copy[$key] = self::copy($element, $copies, $id === null ? $path : [...$path, $id => true]);
+            }
+
+            return $copy;
+        }
+        if (!\is_object($value)) {
+            return $value;
+        }
+        if (isset($copies[$value])) {
+            return $copies[$value];
+        }
+
+        $class = new \ReflectionObject($value);
+        if (!$class->isCloneable()) {
+            return $value;
+        }
+        // Readonly properties can only be initialized once, so user-defined objects are rebuilt
+        // without their constructor; built-in objects keep their internal state through clone.
+        $copy = self::isUserDefined($class) ? $class->newInstanceWithoutConstructor() : clone $value;
+        $copies[$value] = $copy;
+
+        for ($current = $class; $current !== false; $current = $current->getParentClass()) {
+            foreach ($current->getProperties() as $property) {
+                if ($property->isStatic()
+                    || $property->getDeclaringClass()->getName() !== $current->getName()
+                    || !$property->isInitialized($value)
+                    || ($property->isReadOnly() && $property->isInitialized($copy))
+                ) {
+                    continue;
+                }
+                $property->setValue($copy, self::copy($property->getValue($value), $copies, $path));
+            }
+        }
+
+        return $copy;
+    }
+
+    /**
+     * Returns true if neither the class nor any of its parents is a built-in class.
+     *
+     * @param \ReflectionClass<object> $class
+     */
+    private static function isUserDefined(\ReflectionClass $class): bool
+    {
+        for ($current = $class; $current !== false; $current = $current->getParentClass()) {
+            if ($current->isInternal()) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
@@ -15,6 +15,11 @@ use Override;
  */
 final readonly class Err implements Result
 {
+    /**
+     * The leaf error emitted by flattenErrors() in place of an array that contains itself.
+     */
+    private const string RECURSION_MARKER = '*RECURSION*';
+
     /**
      * @param E $value
      */
@@ -65,7 +70,7 @@ final readonly class Err implements Result
     #[Override]
     public function expect(string $message): never
     {
-        throw UnwrapException::withMessage($message, $this->value);
+        throw UnwrapException::withErrorMessage($message, $this->value);
     }
 
     /**
@@ -120,7 +125,7 @@ final readonly class Err implements Result
     #[Override]
     public function mapErr(callable $fn): Result
     {
-        return new self($fn($this->value));
+        return $this->carryMetadataTo(new self($fn($this->value)));
     }
 
     /**
@@ -200,7 +205,32 @@ final readonly class Err implements Result
     #[Override]
     public function orElse(callable $fn): Result
     {
-        return $fn($this->value);
+        return $this->carryMetadataTo($fn($this->value));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function then(callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @template R
+     *
+     * @param callable(E): R $fn
+     *
+     * @return (R is Result<mixed, mixed> ? Result<template-type<R, Result, 'T'>, template-type<R, Result, 'E'>> : Ok<R>)
+     */
+    #[Override]
+    public function catch(callable $fn): Result
+    {
+        $result = $fn($this->value);
+
+        /** @var (R is Result<mixed, mixed> ? Result<template-type<R, Result, 'T'>, template-type<R, Result, 'E'>> : Ok<R>) */
+        return $this->carryMetadataTo($result instanceof Result ? $result : new Ok($result));
     }
 
     #[Override]
@@ -208,4 +238,434 @@ final readonly class Err implements Result
     {
         return $err($this->value);
     }
+
+    /**
+     * @return array{null, E}
+     */
+    #[Override]
+    public function split(): array
+    {
+        return [null, $this->value];
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function mapValues(callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function mapKeys(callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function mapEntries(callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function mapRecursive(callable $fn, int $maxDepth = 64): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function expectType(string $type): never
+    {
+        throw UnwrapException::withErrorMessage('called Result::expectType() on an Err value', $this->value);
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tee(mixed $stream, ?callable $formatter = null): Result
+    {
+        $formatter ??= static fn (mixed $value): string => Results::formatError($value)
+            ?? (\is_scalar($value) || $value instanceof \Stringable ? (string) $value : get_debug_type($value));
+        if (fwrite($stream, $formatter($this->value) . \PHP_EOL) === false) {
+            throw new \RuntimeException('failed to write the Err value to the stream');
+        }
+
+        return $this;
+    }
+
+    /**
+     * @return array{Left: E}
+     */
+    #[Override]
+    public function toEither(): array
+    {
+        return ['Left' => $this->value];
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function expectShape(array $requiredKeys): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function pluck(string $key): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function when(bool $condition, callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function whenWith(callable $condition, callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function ensureNotEmpty(mixed $err, bool $zeroIsEmpty = false): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function toStringOr(string $fallback): string
+    {
+        return $fallback;
+    }
+
+    #[Override]
+    public function mapLazy(callable $fn): LazyResult
+    {
+        return new LazyResult($this, [$fn]);
+    }
+
+    #[Override]
+    public function intoNullable(): null
+    {
+        return null;
+    }
+
+    #[Override]
+    public function intoNullableStrict(): null
+    {
+        return null;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tapOn(array $handlers): Result
+    {
+        if (isset($handlers['err'])) {
+            $handlers['err']($this->value);
+        }
+
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function expectOne(mixed $errIfNotOne): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function orThrowHttp(int $status, ?string $message = null): never
+    {
+        throw new HttpException($status, $this->value, $message ?? \sprintf('HTTP %d', $status));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function cast(string $type): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function isNested(): bool
+    {
+        return $this->value instanceof Result;
+    }
+
+    #[Override]
+    public function withMetadata(string $key, mixed $value): Result
+    {
+        return Metadata::with($this, [$key => $value]);
+    }
+
+    #[Override]
+    public function metadata(string $key): mixed
+    {
+        return Metadata::of($this)[$key] ?? null;
+    }
+
+    /**
+     * Copies this Err's metadata onto a derived Result; the Result's own entries take precedence.
+     *
+     * @template R of Result<mixed, mixed>
+     *
+     * @param R $result
+     *
+     * @return R
+     */
+    private function carryMetadataTo(Result $result): Result
+    {
+        return Metadata::inherit($result, Metadata::of($this));
+    }
+
+    /**
+     * @return Err<LayeredError<mixed>>
+     */
+    #[Override]
+    public function mapErrChain(string $layer): Result
+    {
+        $error = $this->value instanceof LayeredError ? $this->value : new LayeredError($this->value);
+
+        return $this->carryMetadataTo(new self($error->withLayer($layer)));
+    }
+
+    /**
+     * @return E
+     */
+    #[Override]
+    public function expectErrType(string $type): mixed
+    {
+        if (!TypeCheck::matches($this->value, $type)) {
+            throw new \TypeError(\sprintf('Result::expectErrType() expected %s, %s given', $type, get_debug_type($this->value)));
+        }
+
+        return $this->value;
+    }
+
+    /**
+     * @template U
+     * @template V
+     *
+     * @param callable(E): V $recover
+     * @param callable(never): U $mapOk
+     *
+     * @return Ok<V>
+     */
+    #[Override]
+    public function rescueMap(callable $recover, callable $mapOk): Ok
+    {
+        return $this->carryMetadataTo(new Ok($recover($this->value)));
+    }
+
+    #[Override]
+    public function toResponse(array $options = []): array
+    {
+        $error = $this->value;
+        $response = [
+            $options['successKey'] ?? 'success' => false,
+            $options['errorKey'] ?? 'error' => Results::formatError($error) ?? ($error instanceof \Throwable ? $error->getMessage() : $error),
+        ];
+        if (isset($options['errStatus'])) {
+            $status = $options['errStatus'];
+            $response[$options['statusKey'] ?? 'status'] = \is_int($status) ? $status : $status($error);
+        }
+
+        return $response;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function andThenAll(callable $fn): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function constrain(callable $schema): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function recoverIf(callable $predicate, callable $handler): Result
+    {
+        return $predicate($this->value) ? $this->carryMetadataTo(new Ok($handler($this->value))) : $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function expectIn(array $allowed, mixed $err, bool $strict = true): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function snapshot(): Result
+    {
+        return $this->carryMetadataTo(new self(DeepCopy::of($this->value)));
+    }
+
+    #[Override]
+    public function flattenErrors(): Result
+    {
+        if (!\is_array($this->value)) {
+            return $this;
+        }
+        $leaves = [];
+        self::collectLeafErrors($this->value, $leaves);
+
+        return $this->carryMetadataTo(new self($leaves));
+    }
+
+    /**
+     * Appends the leaf errors of a nested error tree to $leaves, depth-first.
+     *
+     * An array reached again through a reference to one of its ancestors is not descended into;
+     * RECURSION_MARKER is appended in its place.
+     *
+     * @param list<mixed> $leaves
+     * @param array<string, true> $path the reference ids of the arrays on the current path
+     */
+    private static function collectLeafErrors(mixed $errors, array &$leaves, array $path = []): void
+    {
+        if ($errors instanceof Result) {
+            if ($errors->isErr()) {
+                self::collectLeafErrors($errors->unwrapErr(), $leaves, $path);
+            }
+
+            return;
+        }
+        if (!\is_array($errors)) {
+            $leaves[] = $errors;
+
+            return;
+        }
+        foreach ($errors as $key => $error) {
+            $id = \ReflectionReference::fromArrayElement($errors, $key)?->getId();
+            if ($id !== null && isset($path[$id])) {
+                $leaves[] = self::RECURSION_MARKER;
+
+                continue;
+            }
+            self::collectLeafErrors($error, $leaves, $id === null ? $path : [...$path, $id => true]);
+        }
+    }
+
+    #[Override]
+    public function pattern(): Pattern
+    {
+        return new Pattern($this);
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tapValueInto(mixed &$target): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function expectInstanceOf(string $class): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return array{'err', E}
+     */
+    #[Override]
+    public function branch(): array
+    {
+        return ['err', $this->value];
+    }
+
+    #[Override]
+    public function withFallbackChain(callable ...$sources): Result
+    {
+        $result = $this;
+        foreach ($sources as $source) {
+            $result = $this->carryMetadataTo($source($this->value));
+            if ($result->isOk()) {
+                return $result;
+            }
+        }
+
+        return $result;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function expectNonNull(mixed $err): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function ensureArray(bool $nullAsEmpty = false): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function __toString(): string
+    {
+        $error = $this->value;
+
+        return \sprintf('Err(%s)', Results::formatError($error) ?? match (true) {
+            $error instanceof \Throwable => $error->getMessage(),
+            \is_scalar($error), $error instanceof \Stringable => (string) $error,
+            default => get_debug_type($error),
+        });
+    }
 }
@@ -0,0 +1,38 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * A framework-agnostic exception carrying an HTTP status code and the error value it came from.
+ *
+ * Thrown by Result::orThrowHttp(); translate it into a response in the framework's exception handler.
+ * If the error value is a \Throwable it is also set as the previous exception.
+ */
+final class HttpException extends \RuntimeException
+{
+    public function __construct(
+        private readonly int $statusCode,
+        private readonly mixed $error,
+        string $message = '',
+    ) {
+        parent::__construct($message, 0, $error instanceof \Throwable ? $error : null);
+    }
+
+    /**
+     * Returns the HTTP status code.
+     */
+    public function getStatusCode(): int
+    {
+        return $this->statusCode;
+    }
+
+    /**
+     * Returns the error value of the Err that was converted.
+     */
+    public function getError(): mixed
+    {
+        return $this->error;
+    }
+}
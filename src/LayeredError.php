@@ -0,0 +1,83 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * An error value wrapped in an ordered stack of context layers, built by Result::mapErrChain().
+ *
+ * The root cause is kept as-is; layers are stored innermost first and formatted outermost first,
+ * as "layerN > ... > layer1: rootMessage".
+ *
+ * @template-covariant E
+ */
+final readonly class LayeredError implements \Stringable
+{
+    /**
+     * @param E $root
+     * @param list<string> $layers innermost first
+     */
+    public function __construct(
+        private mixed $root,
+        private array $layers = [],
+    ) {
+    }
+
+    /**
+     * Returns a copy with a new outermost layer.
+     *
+     * @return self<E>
+     */
+    public function withLayer(string $layer): self
+    {
+        return new self($this->root, [...$this->layers, $layer]);
+    }
+
+    /**
+     * Returns the root cause.
+     *
+     * @return E
+     */
+    public function getRoot(): mixed
+    {
+        return $this->root;
+    }
+
+    /**
+     * Returns the context layers, innermost first.
+     *
+     * @return list<string>
+     */
+    public function getLayers(): array
+    {
+        return $this->layers;
+    }
+
+    /**
+     * Formats the layers outermost first, followed by the root message.
+     *
+     * The root is rendered with the formatter set by Results::setErrorFormatter(), if any. Otherwise
+     * a \Throwable root contributes its message; scalars and Stringable objects are cast to string,
+     * and any other root is described by its type.
+     */
+    public function format(): string
+    {
+        $root = $this->root;
+        $message = Results::formatError($root) ?? match (true) {
+            $root instanceof \Throwable => $root->getMessage(),
+            \is_scalar($root), $root instanceof \Stringable => (string) $root,
+            default => get_debug_type($root),
+        };
+        if ($this->layers === []) {
+            return $message;
+        }
+
+        return \sprintf('%s: %s', implode(' > ', array_reverse($this->layers)), $message);
+    }
+
+    public function __toString(): string
+    {
+        return $this->format();
+    }
+}
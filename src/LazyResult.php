@@ -0,0 +1,65 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * A Result with queued per-element transforms that run only on force().
+ *
+ * Created by Result::mapLazy(). The queued functions are composed and applied to each element
+ * of an array success value in a single pass, so no intermediate array is built per transform.
+ * On an Err nothing runs and force() returns the Err.
+ *
+ * @template T the element type after all queued transforms
+ * @template E
+ */
+final readonly class LazyResult
+{
+    /**
+     * @param Result<mixed, E> $result
+     * @param non-empty-list<callable(never): T> $fns the queued transforms, the last one producing T
+     */
+    public function __construct(
+        private Result $result,
+        private array $fns,
+    ) {
+    }
+
+    /**
+     * Queues another per-element transform.
+     *
+     * @template U
+     *
+     * @param callable(T): U $fn
+     *
+     * @return LazyResult<U, E>
+     */
+    public function mapLazy(callable $fn): self
+    {
+        /** @var LazyResult<U, E> */
+        return new self($this->result, [...$this->fns, $fn]);
+    }
+
+    /**
+     * Applies the queued transforms, in order, to each element of the success value.
+     *
+     * @return Result<array<T>, E>
+     *
+     * @throws \TypeError if the Result is Ok and the success value is not an array
+     */
+    public function force(): Result
+    {
+        /** @var list<callable(mixed): mixed> $fns */
+        $fns = $this->fns;
+
+        /** @var Result<array<T>, E> */
+        return $this->result->mapValues(static function (mixed $value) use ($fns): mixed {
+            foreach ($fns as $fn) {
+                $value = $fn($value);
+            }
+
+            return $value;
+        });
+    }
+}
@@ -0,0 +1,92 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * Stores the metadata entries of Results outside the Results themselves.
+ *
+ * Keeping metadata in a side table leaves it out of the compared state, so `==` (and PHPUnit's
+ * assertEquals()) only look at the payload. Entries are released together with their Result.
+ *
+ * @internal
+ */
+final class Metadata
+{
+    /**
+     * @var \WeakMap<Result<mixed, mixed>, non-empty-array<string, mixed>>|null
+     */
+    private static ?\WeakMap $entries = null;
+
+    /**
+     * Prevents instantiation since this is a static helper.
+     *
+     * @codeCoverageIgnore
+     */
+    private function __construct()
+    {
+    }
+
+    /**
+     * Returns the metadata entries carried by the Result.
+     *
+     * @param Result<mixed, mixed> $result
+     *
+     * @return array<string, mixed>
+     */
+    public static function of(Result $result): array
+    {
+        return self::$entries[$result] ?? [];
+    }
+
+    /**
+     * Returns a copy of the Result carrying the given entries, which replace its own entries with the same keys.
+     *
+     * @template R of Result<mixed, mixed>
+     *
+     * @param R $result
+     * @param array<string, mixed> $entries
+     *
+     * @return R
+     */
+    public static function with(Result $result, array $entries): Result
+    {
+        return self::store(clone $result, $entries + self::of($result));
+    }
+
+    /**
+     * Returns the Result carrying the given entries as well; its own entries take precedence.
+     *
+     * The Result is returned as-is if there is nothing to add, and copied otherwise.
+     *
+     * @template R of Result<mixed, mixed>
+     *
+     * @param R $result
+     * @param array<string, mixed> $entries
+     *
+     * @return R
+     */
+    public static function inherit(Result $result, array $entries): Result
+    {
+        return $entries === [] ? $result : self::store(clone $result, self::of($result) + $entries);
+    }
+
+    /**
+     * @template R of Result<mixed, mixed>
+     *
+     * @param R $result a Result that is not shared yet
+     * @param array<string, mixed> $entries
+     *
+     * @return R
+     */
+    private static function store(Result $result, array $entries): Result
+    {
+        if ($entries !== []) {
+            self::$entries ??= new \WeakMap();
+            self::$entries[$result] = $entries;
+        }
+
+        return $result;
+    }
+}
@@ -110,7 +110,7 @@ final readonly class Ok implements Result
     #[Override]
     public function map(callable $fn): Result
     {
-        return new self($fn($this->value));
+        return $this->carryMetadataTo(new self($fn($this->value)));
     }
 
     /**
@@ -181,7 +181,7 @@ final readonly class Ok implements Result
     #[Override]
     public function andThen(callable $fn): Result
     {
-        return $fn($this->value);
+        return $this->carryMetadataTo($fn($this->value));
     }
 
     /**
@@ -202,9 +202,619 @@ final readonly class Ok implements Result
         return $this;
     }
 
+    /**
+     * @template R
+     *
+     * @param callable(T): R $fn
+     *
+     * @return (R is Result<mixed, mixed> ? Result<template-type<R, Result, 'T'>, template-type<R, Result, 'E'>> : Ok<R>)
+     */
+    #[Override]
+    public function then(callable $fn): Result
+    {
+        $result = $fn($this->value);
+
+        /** @var (R is Result<mixed, mixed> ? Result<template-type<R, Result, 'T'>, template-type<R, Result, 'E'>> : Ok<R>) */
+        return $this->carryMetadataTo($result instanceof Result ? $result : new self($result));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function catch(callable $fn): Result
+    {
+        return $this;
+    }
+
     #[Override]
     public function match(callable $ok, callable $err): mixed
     {
         return $ok($this->value);
     }
+
+    /**
+     * @return array{T, null}
+     */
+    #[Override]
+    public function split(): array
+    {
+        return [$this->value, null];
+    }
+
+    /**
+     * @template U
+     *
+     * @param callable(mixed): U $fn
+     *
+     * @return Ok<array<U>>
+     */
+    #[Override]
+    public function mapValues(callable $fn): Result
+    {
+        return $this->carryMetadataTo(new self(array_map($fn, $this->arrayValue('mapValues'))));
+    }
+
+    /**
+     * @return Ok<array<mixed>>
+     */
+    #[Override]
+    public function mapKeys(callable $fn): Result
+    {
+        $mapped = [];
+        foreach ($this->arrayValue('mapKeys') as $key => $value) {
+            $mapped[$fn($key)] = $value;
+        }
+
+        return $this->carryMetadataTo(new self($mapped));
+    }
+
+    /**
+     * @template K of array-key
+     * @template U
+     *
+     * @param callable(array-key, mixed): array{K, U} $fn
+     *
+     * @return Ok<array<K, U>>
+     */
+    #[Override]
+    public function mapEntries(callable $fn): Result
+    {
+        $mapped = [];
+        foreach ($this->arrayValue('mapEntries') as $key => $value) {
+            $pair = $fn($key, $value);
+            if (!\is_array($pair) || !array_is_list($pair) || \count($pair) !== 2 || !(\is_int($pair[0]) || \is_string($pair[0]))) {
+                throw new \UnexpectedValueException(\sprintf('Result::mapEntries() expects [key, value] pairs, %s given', get_debug_type($pair)));
+            }
+            $mapped[$pair[0]] = $pair[1];
+        }
+
+        return $this->carryMetadataTo(new self($mapped));
+    }
+
+    /**
+     * @return Ok<array<mixed>>
+     */
+    #[Override]
+    public function mapRecursive(callable $fn, int $maxDepth = 64): Result
+    {
+        return $this->carryMetadataTo(new self(self::mapLeaves($this->arrayValue('mapRecursive'), $fn, $maxDepth)));
+    }
+
+    /**
+     * Applies $fn to the scalar leaves of $values, allowing $depth more levels of nesting.
+     *
+     * @param array<mixed> $values
+     * @param callable(int|float|string|bool): mixed $fn
+     *
+     * @return array<mixed>
+     *
+     * @throws \UnexpectedValueException if the nesting exceeds the allowed depth
+     */
+    private static function mapLeaves(array $values, callable $fn, int $depth): array
+    {
+        if ($depth < 1) {
+            throw new \UnexpectedValueException('Result::mapRecursive() exceeded the maximum nesting depth');
+        }
+        $mapped = [];
+        foreach ($values as $key => $value) {
+            $mapped[$key] = match (true) {
+                \is_array($value) => self::mapLeaves($value, $fn, $depth - 1),
+                \is_scalar($value) => $fn($value),
+                default => $value,
+            };
+        }
+
+        return $mapped;
+    }
+
+    /**
+     * Returns the success value, ensuring that it is an array.
+     *
+     * @return array<mixed>
+     *
+     * @throws \TypeError if the success value is not an array
+     */
+    private function arrayValue(string $method): array
+    {
+        if (!\is_array($this->value)) {
+            throw new \TypeError(\sprintf('Result::%s() requires an array success value, %s given', $method, get_debug_type($this->value)));
+        }
+
+        return $this->value;
+    }
+
+    /**
+     * @return T
+     */
+    #[Override]
+    public function expectType(string $type): mixed
+    {
+        if (!TypeCheck::matches($this->value, $type)) {
+            throw new \TypeError(\sprintf('Result::expectType() expected %s, %s given', $type, get_debug_type($this->value)));
+        }
+
+        return $this->value;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tee(mixed $stream, ?callable $formatter = null): Result
+    {
+        $formatter ??= static fn (mixed $value): string => \is_scalar($value) || $value instanceof \Stringable ? (string) $value : get_debug_type($value);
+        if (fwrite($stream, $formatter($this->value) . \PHP_EOL) === false) {
+            throw new \RuntimeException('failed to write the Ok value to the stream');
+        }
+
+        return $this;
+    }
+
+    /**
+     * @return array{Right: T}
+     */
+    #[Override]
+    public function toEither(): array
+    {
+        return ['Right' => $this->value];
+    }
+
+    /**
+     * @param list<string> $requiredKeys
+     *
+     * @return Result<T, array{missing: list<string>}>
+     */
+    #[Override]
+    public function expectShape(array $requiredKeys): Result
+    {
+        $missing = [];
+        foreach ($requiredKeys as $path) {
+            $current = $this->value;
+            foreach (explode('.', $path) as $segment) {
+                if (!\is_array($current) || !\array_key_exists($segment, $current)) {
+                    $missing[] = $path;
+
+                    continue 2;
+                }
+                $current = $current[$segment];
+            }
+        }
+
+        return $missing === [] ? $this : $this->carryMetadataTo(new Err(['missing' => $missing]));
+    }
+
+    /**
+     * @return Result<mixed, \OutOfBoundsException>
+     */
+    #[Override]
+    public function pluck(string $key): Result
+    {
+        $fields = match (true) {
+            \is_array($this->value) => $this->value,
+            \is_object($this->value) => self::publicProperties($this->value),
+            default => null,
+        };
+        if ($fields === null || !\array_key_exists($key, $fields)) {
+            return $this->carryMetadataTo(new Err(new \OutOfBoundsException(\sprintf('key "%s" does not exist in the %s success value', $key, get_debug_type($this->value)))));
+        }
+
+        return $this->carryMetadataTo(new self($fields[$key]));
+    }
+
+    /**
+     * Returns the public properties of an object.
+     *
+     * get_object_vars() sees every property accessible from the calling scope, so it is called
+     * through a closure with no class scope; otherwise the private properties of Ok and Err would
+     * be visible.
+     *
+     * @return array<string, mixed>
+     */
+    private static function publicProperties(object $object): array
+    {
+        /** @var \Closure(object): array<string, mixed> $read */
+        $read = (static fn (object $o): array => get_object_vars($o))->bindTo(null, null);
+
+        return $read($object);
+    }
+
+    #[Override]
+    public function when(bool $condition, callable $fn): Result
+    {
+        return $condition ? $this->carryMetadataTo($fn($this->value)) : $this;
+    }
+
+    #[Override]
+    public function whenWith(callable $condition, callable $fn): Result
+    {
+        return $condition($this->value) ? $this->carryMetadataTo($fn($this->value)) : $this;
+    }
+
+    /**
+     * @template F
+     *
+     * @param F $err
+     *
+     * @return Result<T, F>
+     */
+    #[Override]
+    public function ensureNotEmpty(mixed $err, bool $zeroIsEmpty = false): Result
+    {
+        $isZero = $this->value === 0 || $this->value === 0.0 || $this->value === '0';
+        if (empty($this->value) && ($zeroIsEmpty || !$isZero)) {
+            return $this->carryMetadataTo(new Err($err));
+        }
+
+        return $this;
+    }
+
+    #[Override]
+    public function toStringOr(string $fallback): string
+    {
+        if (\is_string($this->value) || \is_int($this->value) || \is_float($this->value) || $this->value instanceof \Stringable) {
+            return (string) $this->value;
+        }
+
+        return $fallback;
+    }
+
+    #[Override]
+    public function mapLazy(callable $fn): LazyResult
+    {
+        return new LazyResult($this, [$fn]);
+    }
+
+    /**
+     * @return T
+     */
+    #[Override]
+    public function intoNullable(): mixed
+    {
+        return $this->value;
+    }
+
+    /**
+     * @return T
+     */
+    #[Override]
+    public function intoNullableStrict(): mixed
+    {
+        if ($this->value === null) {
+            throw UnwrapException::nullOnOk();
+        }
+
+        return $this->value;
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tapOn(array $handlers): Result
+    {
+        if (isset($handlers['ok'])) {
+            $handlers['ok']($this->value);
+        }
+
+        return $this;
+    }
+
+    /**
+     * @template F
+     *
+     * @param F $errIfNotOne
+     *
+     * @return Result<mixed, F>
+     */
+    #[Override]
+    public function expectOne(mixed $errIfNotOne): Result
+    {
+        $values = $this->arrayValue('expectOne');
+        if (\count($values) !== 1) {
+            return $this->carryMetadataTo(new Err($errIfNotOne));
+        }
+
+        return $this->carryMetadataTo(new self(reset($values)));
+    }
+
+    /**
+     * @return T
+     */
+    #[Override]
+    public function orThrowHttp(int $status, ?string $message = null): mixed
+    {
+        return $this->value;
+    }
+
+    /**
+     * @return Result<int|float|string|bool, \UnexpectedValueException>
+     */
+    #[Override]
+    public function cast(string $type): Result
+    {
+        $coerced = $this->coerce($type);
+        if ($coerced === null) {
+            return $this->carryMetadataTo(new Err(new \UnexpectedValueException(\sprintf('cannot cast %s to %s', get_debug_type($this->value), $type))));
+        }
+
+        return $this->carryMetadataTo(new self($coerced));
+    }
+
+    /**
+     * Coerces the success value to the given scalar type, or returns null if it cannot be coerced.
+     *
+     * @throws \InvalidArgumentException if $type is not one of the supported types
+     */
+    private function coerce(string $type): int|float|string|bool|null
+    {
+        $value = $this->value;
+
+        return match ($type) {
+            'int' => match (true) {
+                \is_int($value) => $value,
+                \is_float($value) && is_finite($value) && (float) (int) $value === $value => (int) $value,
+                \is_string($value) && \is_numeric($value) && preg_match('/\A[+-]?\d+\z/', $value) === 1 => \is_int($value + 0) ? $value + 0 : null,
+                default => null,
+            },
+            'float' => match (true) {
+                \is_float($value) => $value,
+                \is_int($value) => (float) $value,
+                \is_string($value) && \is_numeric($value) => (float) $value,
+                default => null,
+            },
+            'string' => match (true) {
+                \is_string($value) => $value,
+                \is_int($value), \is_float($value), $value instanceof \Stringable => (string) $value,
+                default => null,
+            },
+            'bool' => match (true) {
+                \is_bool($value) => $value,
+                $value === 0, $value === 1 => $value === 1,
+                \is_string($value) => match (strtolower($value)) {
+                    '1', 'true' => true,
+                    '0', 'false' => false,
+                    default => null,
+                },
+                default => null,
+            },
+            default => throw new \InvalidArgumentException(\sprintf('Result::cast() does not support type "%s"', $type)),
+        };
+    }
+
+    #[Override]
+    public function isNested(): bool
+    {
+        return $this->value instanceof Result;
+    }
+
+    #[Override]
+    public function withMetadata(string $key, mixed $value): Result
+    {
+        return Metadata::with($this, [$key => $value]);
+    }
+
+    #[Override]
+    public function metadata(string $key): mixed
+    {
+        return Metadata::of($this)[$key] ?? null;
+    }
+
+    /**
+     * Copies this Ok's metadata onto a derived Result; the Result's own entries take precedence.
+     *
+     * @template R of Result<mixed, mixed>
+     *
+     * @param R $result
+     *
+     * @return R
+     */
+    private function carryMetadataTo(Result $result): Result
+    {
+        return Metadata::inherit($result, Metadata::of($this));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function mapErrChain(string $layer): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function expectErrType(string $type): never
+    {
+        throw UnwrapException::withMessage('called Result::expectErrType() on an Ok value', $this->value);
+    }
+
+    /**
+     * @template U
+     * @template V
+     *
+     * @param callable(never): V $recover
+     * @param callable(T): U $mapOk
+     *
+     * @return Ok<U>
+     */
+    #[Override]
+    public function rescueMap(callable $recover, callable $mapOk): self
+    {
+        return $this->carryMetadataTo(new self($mapOk($this->value)));
+    }
+
+    #[Override]
+    public function toResponse(array $options = []): array
+    {
+        $response = [
+            $options['successKey'] ?? 'success' => true,
+            $options['dataKey'] ?? 'data' => $this->value,
+        ];
+        if (isset($options['okStatus'])) {
+            $response[$options['statusKey'] ?? 'status'] = $options['okStatus'];
+        }
+
+        return $response;
+    }
+
+    #[Override]
+    public function andThenAll(callable $fn): Result
+    {
+        return $this->carryMetadataTo(Results::combine($fn($this->value)));
+    }
+
+    #[Override]
+    public function constrain(callable $schema): Result
+    {
+        return $this->carryMetadataTo($schema($this->value));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function recoverIf(callable $predicate, callable $handler): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @template F
+     *
+     * @param array<mixed> $allowed
+     * @param F $err
+     *
+     * @return Result<T, F>
+     */
+    #[Override]
+    public function expectIn(array $allowed, mixed $err, bool $strict = true): Result
+    {
+        return \in_array($this->value, $allowed, $strict) ? $this : $this->carryMetadataTo(new Err($err));
+    }
+
+    #[Override]
+    public function snapshot(): Result
+    {
+        return $this->carryMetadataTo(new self(DeepCopy::of($this->value)));
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function flattenErrors(): Result
+    {
+        return $this;
+    }
+
+    #[Override]
+    public function pattern(): Pattern
+    {
+        return new Pattern($this);
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function tapValueInto(mixed &$target): Result
+    {
+        $target = $this->value;
+
+        return $this;
+    }
+
+    /**
+     * @template C of object
+     *
+     * @param class-string<C> $class
+     *
+     * @return Result<C, \UnexpectedValueException>
+     */
+    #[Override]
+    public function expectInstanceOf(string $class): Result
+    {
+        if (!$this->value instanceof $class) {
+            return $this->carryMetadataTo(new Err(new \UnexpectedValueException(\sprintf('expected an instance of %s, %s given', $class, get_debug_type($this->value)))));
+        }
+
+        return $this->carryMetadataTo(new self($this->value));
+    }
+
+    /**
+     * @return array{'ok', T}
+     */
+    #[Override]
+    public function branch(): array
+    {
+        return ['ok', $this->value];
+    }
+
+    /**
+     * @return $this
+     */
+    #[Override]
+    public function withFallbackChain(callable ...$sources): Result
+    {
+        return $this;
+    }
+
+    /**
+     * @template F
+     *
+     * @param F $err
+     *
+     * @return Result<T, F>
+     */
+    #[Override]
+    public function expectNonNull(mixed $err): Result
+    {
+        return $this->value === null ? $this->carryMetadataTo(new Err($err)) : $this;
+    }
+
+    /**
+     * @return Ok<array<mixed>>
+     */
+    #[Override]
+    public function ensureArray(bool $nullAsEmpty = false): Result
+    {
+        return match (true) {
+            \is_array($this->value) => $this->carryMetadataTo(new self($this->value)),
+            $this->value === null && $nullAsEmpty => $this->carryMetadataTo(new self([])),
+            default => $this->carryMetadataTo(new self([$this->value])),
+        };
+    }
+
+    #[Override]
+    public function __toString(): string
+    {
+        $value = $this->value;
+
+        return \sprintf('Ok(%s)', match (true) {
+            $value instanceof \Throwable => $value->getMessage(),
+            \is_scalar($value), $value instanceof \Stringable => (string) $value,
+            default => get_debug_type($value),
+        });
+    }
 }
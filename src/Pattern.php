@@ -0,0 +1,67 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * A fluent matcher over a Result, created by Result::pattern().
+ *
+ * Both arms must be given with ok() and err() before run(), which calls the arm matching the
+ * variant and returns its value. Each call returns a new matcher; the original is unchanged.
+ *
+ * @template T
+ * @template E
+ */
+final readonly class Pattern
+{
+    /**
+     * @param Result<T, E> $result
+     * @param (\Closure(T): mixed)|null $ok
+     * @param (\Closure(E): mixed)|null $err
+     */
+    public function __construct(
+        private Result $result,
+        private ?\Closure $ok = null,
+        private ?\Closure $err = null,
+    ) {
+    }
+
+    /**
+     * Sets the arm called with the success value.
+     *
+     * @param callable(T): mixed $fn
+     *
+     * @return Pattern<T, E>
+     */
+    public function ok(callable $fn): self
+    {
+        return new self($this->result, $fn(...), $this->err);
+    }
+
+    /**
+     * Sets the arm called with the error value.
+     *
+     * @param callable(E): mixed $fn
+     *
+     * @return Pattern<T, E>
+     */
+    public function err(callable $fn): self
+    {
+        return new self($this->result, $this->ok, $fn(...));
+    }
+
+    /**
+     * Calls the arm matching the variant and returns its value.
+     *
+     * @throws \LogicException if either arm has not been set
+     */
+    public function run(): mixed
+    {
+        if ($this->ok === null || $this->err === null) {
+            throw new \LogicException(\sprintf('Pattern::run() requires both arms, missing: %s', $this->ok === null ? ($this->err === null ? 'ok, err' : 'ok') : 'err'));
+        }
+
+        return $this->result->match($this->ok, $this->err);
+    }
+}
@@ -231,6 +231,34 @@ interface Result
      */
     public function orElse(callable $fn): self;
 
+    /**
+     * Promise-style alias of map() / andThen(): applies a function to the success value.
+     *
+     * Auto-wrap rule: if the callback returns a Result it is returned as-is (like andThen()),
+     * any other return value is wrapped in Ok (like map()). On failure the callback is not called.
+     *
+     * @template R
+     *
+     * @param callable(T): R $fn
+     *
+     * @return (R is Result<mixed, mixed> ? Result<template-type<R, Result, 'T'>, E|template-type<R, Result, 'E'>> : Result<R, E>)
+     */
+    public function then(callable $fn): self;
+
+    /**
+     * Promise-style alias of orElse(): applies a function to the error value to recover.
+     *
+     * Follows the same auto-wrap rule as then(): a returned Result is used as-is, any other
+     * return value is wrapped in Ok. On success the callback is not called.
+     *
+     * @template R
+     *
+     * @param callable(E): R $fn
+     *
+     * @return (R is Result<mixed, mixed> ? Result<T|template-type<R, Result, 'T'>, template-type<R, Result, 'E'>> : Result<T|R, never>)
+     */
+    public function catch(callable $fn): self;
+
     /**
      * Applies ok on success, or err on failure.
      *
@@ -243,4 +271,524 @@ interface Result
      * @return U|V the result of the applied function
      */
     public function match(callable $ok, callable $err): mixed;
+
+    /**
+     * Splits the Result into a Go-style [value, error] tuple.
+     *
+     * Returns [value, null] for Ok and [null, error] for Err. Note that both Ok(null) and
+     * Err(null) yield [null, null], so the tuple alone cannot tell them apart.
+     *
+     * @return array{T, null}|array{null, E}
+     */
+    public function split(): array;
+
+    /**
+     * Applies a function to each value of an array success value, preserving the keys.
+     *
+     * @template U
+     *
+     * @param callable(mixed): U $fn
+     *
+     * @return Result<array<U>, E>
+     *
+     * @throws \TypeError if $this is Ok and the success value is not an array
+     */
+    public function mapValues(callable $fn): self;
+
+    /**
+     * Applies a function to each key of an array success value, preserving the values.
+     *
+     * When several keys map to the same new key, the last entry wins.
+     *
+     * @param callable(array-key): array-key $fn
+     *
+     * @return Result<array<mixed>, E>
+     *
+     * @throws \TypeError if $this is Ok and the success value is not an array
+     */
+    public function mapKeys(callable $fn): self;
+
+    /**
+     * Applies a function to each entry of an array success value, rebuilding the array from the returned pairs.
+     *
+     * $fn receives the key and the value and returns a [$newKey, $newValue] pair. When several
+     * entries map to the same new key, the last entry wins.
+     *
+     * @template K of array-key
+     * @template U
+     *
+     * @param callable(array-key, mixed): array{K, U} $fn
+     *
+     * @return Result<array<K, U>, E>
+     *
+     * @throws \TypeError if $this is Ok and the success value is not an array
+     * @throws \UnexpectedValueException if $fn does not return a [key, value] pair
+     */
+    public function mapEntries(callable $fn): self;
+
+    /**
+     * Applies a function to every scalar leaf of a nested array success value, preserving the structure.
+     *
+     * Keys are preserved at every level; leaves that are neither arrays nor scalars (null,
+     * objects, ...) are kept as-is. Nesting deeper than $maxDepth levels is rejected.
+     *
+     * @param callable(int|float|string|bool): mixed $fn
+     *
+     * @return Result<array<mixed>, E>
+     *
+     * @throws \TypeError if $this is Ok and the success value is not an array
+     * @throws \UnexpectedValueException if $this is Ok and the array is nested deeper than $maxDepth
+     */
+    public function mapRecursive(callable $fn, int $maxDepth = 64): self;
+
+    /**
+     * Returns the success value after checking its type at runtime.
+     *
+     * $type is a scalar/pseudo type name (int, string, array, ...) or a class/interface name,
+     * and may be a union such as "int|string" or a nullable type such as "?int".
+     *
+     * @return ($this is Ok<mixed> ? T : never)
+     *
+     * @throws \TypeError if $this is Ok and the success value does not match $type
+     * @throws UnwrapException if $this is Err
+     */
+    public function expectType(string $type): mixed;
+
+    /**
+     * Writes the success value or the error value to a stream as a line, and returns the Result unchanged.
+     *
+     * The default formatter casts scalars and Stringable objects to string and falls back to the
+     * type name for anything else.
+     *
+     * @param resource $stream a writable stream
+     * @param (callable(T|E): string)|null $formatter
+     *
+     * @return Result<T, E>
+     *
+     * @throws \RuntimeException if writing to the stream fails
+     */
+    public function tee(mixed $stream, ?callable $formatter = null): self;
+
+    /**
+     * Exports the Result as an Either-style tagged array.
+     *
+     * Follows the Either convention where Right is the success side and Left is the error side:
+     * ['Right' => value] for Ok and ['Left' => error] for Err.
+     *
+     * @return array{Right: T}|array{Left: E}
+     */
+    public function toEither(): array;
+
+    /**
+     * Checks that an associative array success value contains all the required keys.
+     *
+     * Keys may be dot paths into nested arrays ('user.id'). Returns the Result unchanged if every
+     * key exists, otherwise Err(['missing' => [...]]) listing the missing keys in the given order.
+     * A success value that is not an array is missing every key.
+     *
+     * @param list<string> $requiredKeys
+     *
+     * @return Result<T, E|array{missing: list<string>}>
+     */
+    public function expectShape(array $requiredKeys): self;
+
+    /**
+     * Extracts a field from an array or object success value.
+     *
+     * Reads an array key or a public object property. If the key is missing (or the success value
+     * is neither an array nor an object) an Err holding an \OutOfBoundsException is returned.
+     *
+     * @return Result<mixed, E|\OutOfBoundsException>
+     */
+    public function pluck(string $key): self;
+
+    /**
+     * Applies an andThen()-style function only if the condition holds; otherwise returns the Result unchanged.
+     *
+     * Err is always returned unchanged.
+     *
+     * @template U
+     * @template F
+     *
+     * @param callable(T): Result<U, F> $fn
+     *
+     * @return Result<T|U, E|F>
+     */
+    public function when(bool $condition, callable $fn): self;
+
+    /**
+     * Like when(), but the condition is computed lazily from the success value.
+     *
+     * @template U
+     * @template F
+     *
+     * @param callable(T): bool $condition
+     * @param callable(T): Result<U, F> $fn
+     *
+     * @return Result<T|U, E|F>
+     */
+    public function whenWith(callable $condition, callable $fn): self;
+
+    /**
+     * Turns an empty success value into an Err.
+     *
+     * "Empty" follows PHP's empty() (null, false, '', [] ...), except that the zeros 0, 0.0 and '0'
+     * count as non-empty unless $zeroIsEmpty is true. Err is returned unchanged.
+     *
+     * @template F
+     *
+     * @param F $err
+     *
+     * @return Result<T, E|F>
+     */
+    public function ensureNotEmpty(mixed $err, bool $zeroIsEmpty = false): self;
+
+    /**
+     * Returns the success value as a string, or the fallback.
+     *
+     * Strings, ints, floats and Stringable objects are cast to string. The fallback is returned
+     * for an Err and for any other success value (bool, null, arrays, non-Stringable objects).
+     */
+    public function toStringOr(string $fallback): string;
+
+    /**
+     * Queues a per-element transform of an array success value without running it.
+     *
+     * Further transforms can be queued with LazyResult::mapLazy(); LazyResult::force() then
+     * applies all of them, composed, to each element in a single pass (like mapValues()).
+     *
+     * @template U
+     *
+     * @param callable(mixed): U $fn
+     *
+     * @return LazyResult<U, E>
+     */
+    public function mapLazy(callable $fn): LazyResult;
+
+    /**
+     * Returns the success value, or null on failure.
+     *
+     * Unlike in Rust, an Ok can hold null, so an Ok(null) and an Err both give null here;
+     * use intoNullableStrict() where that ambiguity matters.
+     *
+     * @return ($this is Ok<mixed> ? T : null)
+     */
+    public function intoNullable(): mixed;
+
+    /**
+     * Returns the success value, or null on failure, rejecting an ambiguous Ok(null).
+     *
+     * @return ($this is Ok<mixed> ? T : null)
+     *
+     * @throws UnwrapException if $this is Ok and the success value is null
+     */
+    public function intoNullableStrict(): mixed;
+
+    /**
+     * Calls the handler matching the variant, if given, and returns the Result unchanged.
+     *
+     * Both the 'ok' and the 'err' handler are optional; a missing handler is simply skipped.
+     *
+     * @param array{ok?: callable(T): mixed, err?: callable(E): mixed} $handlers
+     *
+     * @return Result<T, E>
+     */
+    public function tapOn(array $handlers): self;
+
+    /**
+     * Unwraps the single element of an array success value.
+     *
+     * Returns Ok with the element itself (its key is discarded) if the array has exactly one
+     * element, otherwise Err($errIfNotOne). Err is returned unchanged.
+     *
+     * @template F
+     *
+     * @param F $errIfNotOne
+     *
+     * @return Result<mixed, E|F>
+     *
+     * @throws \TypeError if $this is Ok and the success value is not an array
+     */
+    public function expectOne(mixed $errIfNotOne): self;
+
+    /**
+     * Returns the success value, or throws an HttpException on failure.
+     *
+     * The exception carries the status code and the error value; its message defaults to "HTTP <status>".
+     *
+     * @return ($this is Ok<mixed> ? T : never)
+     *
+     * @throws HttpException if $this is Err
+     */
+    public function orThrowHttp(int $status, ?string $message = null): mixed;
+
+    /**
+     * Coerces the success value to a scalar type.
+     *
+     * Coercion is strict: "int" accepts ints, integral floats and integer strings ("12abc" and
+     * out-of-range values are rejected); "float" accepts floats, ints and numeric strings;
+     * "string" accepts strings, ints, floats and Stringable objects; "bool" accepts bools, 0/1
+     * and the strings "1", "0", "true" and "false" (case-insensitive). A value that cannot be
+     * coerced yields an Err holding an \UnexpectedValueException. Err is returned unchanged.
+     *
+     * @param 'int'|'float'|'string'|'bool' $type
+     *
+     * @return Result<int|float|string|bool, E|\UnexpectedValueException>
+     *
+     * @throws \InvalidArgumentException if $type is not one of the supported types
+     */
+    public function cast(string $type): self;
+
+    /**
+     * Returns true if the success value (for Ok) or the error value (for Err) is itself a Result.
+     *
+     * Useful for deciding whether Results::flatten() applies.
+     *
+     * @return bool
+     */
+    public function isNested(): bool;
+
+    /**
+     * Returns a copy of the Result carrying a metadata entry (e.g. a trace id) alongside the payload.
+     *
+     * Metadata is side-channel data: it never changes the payload, and it is copied onto the
+     * Results derived in a chain (map, mapErr, andThen, orElse, then, catch, ...). An existing
+     * entry with the same key is replaced. Metadata is not compared by `==`.
+     *
+     * @return Result<T, E>
+     */
+    public function withMetadata(string $key, mixed $value): self;
+
+    /**
+     * Returns the metadata entry stored under the key, or null if there is none.
+     */
+    public function metadata(string $key): mixed;
+
+    /**
+     * Pushes a context layer onto the error, building a LayeredError that preserves the root cause.
+     *
+     * If the error value is not a LayeredError yet, it becomes the root of a new one. The
+     * formatted error reads "layerN > ... > layer1: rootMessage". Ok is returned unchanged.
+     *
+     * @return Result<T, LayeredError<mixed>>
+     */
+    public function mapErrChain(string $layer): self;
+
+    /**
+     * Returns the error value after checking its type at runtime.
+     *
+     * $type accepts the same type names as expectType(): scalar/pseudo types, class/interface
+     * names, unions and nullable types.
+     *
+     * @return ($this is Err<mixed> ? E : never)
+     *
+     * @throws \TypeError if $this is Err and the error value does not match $type
+     * @throws UnwrapException if $this is Ok
+     */
+    public function expectErrType(string $type): mixed;
+
+    /**
+     * Maps the success value, or recovers from the error; either way the result is Ok.
+     *
+     * Like a bimap whose error branch always recovers: Ok(v) becomes Ok($mapOk(v)) and
+     * Err(e) becomes Ok($recover(e)).
+     *
+     * @template U
+     * @template V
+     *
+     * @param callable(E): V $recover
+     * @param callable(T): U $mapOk
+     *
+     * @return Ok<U|V>
+     */
+    public function rescueMap(callable $recover, callable $mapOk): Ok;
+
+    /**
+     * Builds a conventional API response envelope.
+     *
+     * Ok gives ['success' => true, 'data' => value] and Err gives ['success' => false, 'error' => error].
+     * The error is rendered with the formatter set by Results::setErrorFormatter(); without one,
+     * a \Throwable is replaced by its message and any other error is used as-is. The key names
+     * can be changed with the 'successKey', 'dataKey' and 'errorKey' options. With 'okStatus'
+     * (an int) or 'errStatus' (an int, or a callable mapping the error to an int), the status
+     * code is added under 'statusKey' (default 'status').
+     *
+     * @param array{successKey?: string, dataKey?: string, errorKey?: string, statusKey?: string, okStatus?: int, errStatus?: int|(callable(E): int)} $options
+     *
+     * @return array<string, mixed>
+     */
+    public function toResponse(array $options = []): array;
+
+    /**
+     * Fans out from the success value into several Results and collects them.
+     *
+     * $fn returns an iterable of Results, which are combined as with Results::combine(): Ok with
+     * the list of values, or the first Err. Err is returned unchanged without calling $fn.
+     *
+     * @template U
+     * @template F
+     *
+     * @param callable(T): iterable<Result<U, F>> $fn
+     *
+     * @return Result<list<U>, E|F>
+     */
+    public function andThenAll(callable $fn): self;
+
+    /**
+     * Validates the success value against a schema; an intent-revealing andThen() for validation.
+     *
+     * $schema returns Ok with the (possibly coerced) value, or Err with the validation details.
+     * Err is returned unchanged without calling $schema.
+     *
+     * @template U
+     * @template F
+     *
+     * @param callable(T): Result<U, F> $schema
+     *
+     * @return Result<U, E|F>
+     */
+    public function constrain(callable $schema): self;
+
+    /**
+     * Recovers from the error only if it satisfies a predicate.
+     *
+     * On Err(e), returns Ok($handler(e)) if $predicate(e) is true and the Err unchanged otherwise
+     * ($handler is not called). Ok is returned unchanged.
+     *
+     * @template U
+     *
+     * @param callable(E): bool $predicate
+     * @param callable(E): U $handler
+     *
+     * @return Result<T|U, E>
+     */
+    public function recoverIf(callable $predicate, callable $handler): self;
+
+    /**
+     * Keeps the Result if the success value is one of the allowed values, otherwise returns Err($err).
+     *
+     * Membership uses strict comparison by default; pass $strict = false for loose comparison.
+     * Err is returned unchanged.
+     *
+     * @template F
+     *
+     * @param array<mixed> $allowed
+     * @param F $err
+     *
+     * @return Result<T, E|F>
+     */
+    public function expectIn(array $allowed, mixed $err, bool $strict = true): self;
+
+    /**
+     * Returns a copy of the Result whose payload is isolated from later mutations of the original.
+     *
+     * Arrays and objects are copied recursively, including the objects held in object properties;
+     * scalars are immutable and need no copying. An object referenced several times is copied once,
+     * so aliasing is preserved in the copy. Objects that cannot be cloned, such as enum cases, are shared.
+     * An array that contains itself through a reference is copied with "*RECURSION*" in place of the cycle.
+     *
+     * @return Result<T, E>
+     */
+    public function snapshot(): self;
+
+    /**
+     * Flattens a nested tree of errors into a single list of leaf errors.
+     *
+     * On an Err holding an array, nested arrays are descended into and nested Err values are
+     * unwrapped (their errors are flattened too), while nested Ok values are dropped. Every other
+     * value is a leaf error; leaves are listed depth-first in order and their keys are discarded.
+     * An array that contains itself through a reference yields a "*RECURSION*" leaf instead of being
+     * descended into again. An Err holding a non-array error and Ok are returned unchanged.
+     *
+     * @return Result<T, E|list<mixed>>
+     */
+    public function flattenErrors(): self;
+
+    /**
+     * Starts a fluent matcher: ->pattern()->ok($fn)->err($fn)->run() is a readable alternative to match().
+     *
+     * @return Pattern<T, E>
+     */
+    public function pattern(): Pattern;
+
+    /**
+     * Assigns the success value to a variable by reference, and returns the Result unchanged.
+     *
+     * On Err the variable is left untouched.
+     *
+     * @return Result<T, E>
+     */
+    public function tapValueInto(mixed &$target): self;
+
+    /**
+     * Keeps the Result if the success value is an instance of the class or interface.
+     *
+     * Otherwise returns an Err holding an \UnexpectedValueException naming the expected class
+     * and the actual type. Err is returned unchanged.
+     *
+     * @template C of object
+     *
+     * @param class-string<C> $class
+     *
+     * @return Result<C, E|\UnexpectedValueException>
+     */
+    public function expectInstanceOf(string $class): self;
+
+    /**
+     * Returns a tagged two-element list for destructuring: ['ok', value] for Ok and ['err', error] for Err.
+     *
+     * The tags are always the exact strings 'ok' and 'err', e.g. `[$tag, $payload] = $result->branch();`
+     * followed by `match ($tag) { 'ok' => ..., 'err' => ... }`.
+     *
+     * @return array{'ok', T}|array{'err', E}
+     */
+    public function branch(): array;
+
+    /**
+     * Tries ordered fallback sources on failure; orElse() generalized to several fallbacks.
+     *
+     * On Err, calls each source with the original error in order and returns the first Ok; the
+     * remaining sources are not called. If every source fails, the last source's Err is returned
+     * (the Err itself if no sources are given). Ok is returned unchanged without calling any source.
+     *
+     * @template U
+     * @template F
+     *
+     * @param callable(E): Result<U, F> ...$sources
+     *
+     * @return Result<T|U, E|F>
+     */
+    public function withFallbackChain(callable ...$sources): self;
+
+    /**
+     * Turns Ok(null) into Err($err), keeping every other Result unchanged.
+     *
+     * Only null is rejected: falsy values such as 0, false and '' remain Ok.
+     *
+     * @template F
+     *
+     * @param F $err
+     *
+     * @return Result<T, E|F>
+     */
+    public function expectNonNull(mixed $err): self;
+
+    /**
+     * Normalizes a "one or many" success value to an array.
+     *
+     * An array is kept as-is and any other value is wrapped as [value]. null becomes [null] by
+     * default, or [] with $nullAsEmpty. Err is returned unchanged.
+     *
+     * @return Result<array<mixed>, E>
+     */
+    public function ensureArray(bool $nullAsEmpty = false): self;
+
+    /**
+     * Renders the Result as "Ok(value)" or "Err(error)".
+     *
+     * The error is rendered with the formatter set by Results::setErrorFormatter(), if any. Otherwise
+     * a \Throwable contributes its message, scalars and Stringable objects are cast to string, and
+     * any other value is described by its type; success values are rendered the same way.
+     */
+    public function __toString(): string;
 }
@@ -0,0 +1,72 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * Builds Results whose errors all come from one preset error factory.
+ *
+ * Useful in a module with a single error type: the failure paths only pass a reason,
+ * and the factory turns it into the error value. Created via Results::withErrorType().
+ *
+ * @template E
+ */
+final readonly class ResultBuilder
+{
+    /**
+     * @param \Closure(mixed): E $errorFactory
+     */
+    public function __construct(
+        private \Closure $errorFactory,
+    ) {
+    }
+
+    /**
+     * Creates a success.
+     *
+     * @template T
+     *
+     * @param T $value
+     *
+     * @return Ok<T>
+     */
+    public function ok(mixed $value): Ok
+    {
+        return new Ok($value);
+    }
+
+    /**
+     * Creates a failure whose error is built from the reason by the preset factory.
+     *
+     * @return Err<E>
+     */
+    public function fail(mixed $reason = null): Err
+    {
+        return new Err(($this->errorFactory)($reason));
+    }
+
+    /**
+     * Returns Ok(true) if the condition holds, otherwise a failure built from the reason.
+     *
+     * @return Result<true, E>
+     */
+    public function fromBool(bool $condition, mixed $reason = null): Result
+    {
+        return $condition ? new Ok(true) : $this->fail($reason);
+    }
+
+    /**
+     * Wraps a non-null value in Ok, or returns a failure built from the reason for null.
+     *
+     * @template T
+     *
+     * @param T|null $value
+     *
+     * @return Result<T, E>
+     */
+    public function fromNullable(mixed $value, mixed $reason = null): Result
+    {
+        return $value !== null ? new Ok($value) : $this->fail($reason);
+    }
+}
@@ -9,6 +9,13 @@ namespace Valbeat\Result;
  */
 final class Results
 {
+    /**
+     * The formatter set by setErrorFormatter(), or null for the built-in rendering.
+     *
+     * @var (\Closure(mixed): string)|null
+     */
+    private static ?\Closure $errorFormatter = null;
+
     /**
      * Prevents instantiation since this is a static helper.
      *
@@ -39,22 +46,80 @@ final class Results
         }
     }
 
+    /**
+     * Adapts a callable that follows PHP's "false on failure" convention.
+     *
+     * Returns Err($falseError) if the callable returns exactly false, Err with the Throwable if it
+     * throws, and Ok with the return value otherwise (including other falsy values such as 0 or null).
+     *
+     * @template T
+     * @template F
+     *
+     * @param callable(): (T|false) $fn
+     * @param F $falseError
+     *
+     * @return Result<T, F|\Throwable>
+     */
+    public static function adapt(callable $fn, mixed $falseError): Result
+    {
+        try {
+            $value = $fn();
+        } catch (\Throwable $e) {
+            return new Err($e);
+        }
+        if ($value === false) {
+            return new Err($falseError);
+        }
+
+        return new Ok($value);
+    }
+
+    /**
+     * Runs a validator against a value and wraps the outcome in a Result.
+     *
+     * The validator passes only when it returns exactly true (strict comparison); the value is then
+     * wrapped in Ok. Any other return value (an error message, a list of violations, false, ...)
+     * is treated as the failure details and wrapped in Err as-is.
+     *
+     * @template T
+     * @template E
+     *
+     * @param T $value
+     * @param callable(T): (true|E) $validator
+     *
+     * @return Result<T, E>
+     */
+    public static function fromValidator(mixed $value, callable $validator): Result
+    {
+        $outcome = $validator($value);
+        if ($outcome === true) {
+            return new Ok($value);
+        }
+
+        return new Err($outcome);
+    }
+
     /**
      * Combines multiple Results into one.
      *
      * If all are successes, returns the list of values as an Ok; if any failure is present, returns the first Err.
+     * With $flatten, an Ok holding a Result is flattened one level first, so Ok(Ok(x)) contributes x
+     * and Ok(Err(e)) short-circuits with Err(e).
      *
      * @template T
      * @template E
      *
      * @param iterable<Result<T, E>> $results
      *
-     * @return Result<list<T>, E>
+     * @return ($flatten is true ? Result<list<mixed>, mixed> : Result<list<T>, E>)
      */
-    public static function combine(iterable $results): Result
+    public static function combine(iterable $results, bool $flatten = false): Result
     {
         $values = [];
         foreach ($results as $result) {
+            if ($flatten && $result->isOk() && $result->unwrap() instanceof Result) {
+                $result = $result->unwrap();
+            }
             if ($result->isErr()) {
                 return $result;
             }
@@ -65,11 +130,45 @@ final class Results
     }
 
     /**
-     * Flattens a nested Result by one level.
+     * Combines multiple Results into a collection object created by the caller.
+     *
+     * The collection is created by $factory and every success value is added to it with
+     * $append($collection, $value), so it must be an object that is mutated in place
+     * (e.g. an SplStack or a Doctrine ArrayCollection). Short-circuits on the first Err.
+     *
+     * @template T
+     * @template E
+     * @template C of object
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param callable(): C $factory
+     * @param callable(C, T): mixed $append
+     *
+     * @return Result<C, E>
+     */
+    public static function collectInto(iterable $results, callable $factory, callable $append): Result
+    {
+        $collection = $factory();
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                return $result;
+            }
+            $append($collection, $result->unwrap());
+        }
+
+        return new Ok($collection);
+    }
+
+    /**
+     * Flattens a nested Result by up to $depth levels (one by default).
+     *
+     * Flattening stops early at an Err or at an Ok whose value is not a Result;
+     * pass PHP_INT_MAX to flatten completely.
      *
      * This is not an instance method because PHPStan's conditional types cannot
      * decompose the template T into Result<U, F> (there is no infer). As a static
      * helper, the inner type can be inferred precisely from the parameter-side template.
+     * That inference covers one level only, so for a $depth other than 1 the types are not tracked.
      *
      * @template T
      * @template E1
@@ -77,10 +176,1344 @@ final class Results
      *
      * @param Result<Result<T, E2>, E1> $result
      *
-     * @return Result<T, E1|E2>
+     * @return ($depth is 1 ? Result<T, E1|E2> : Result<mixed, mixed>)
+     *
+     * @throws \InvalidArgumentException if $depth is negative
+     */
+    public static function flatten(Result $result, int $depth = 1): Result
+    {
+        if ($depth < 0) {
+            throw new \InvalidArgumentException(\sprintf('$depth must not be negative, %d given', $depth));
+        }
+
+        $current = $result;
+        for ($level = 0; $level < $depth && $current->isOk(); $level++) {
+            $inner = $current->unwrap();
+            if (!$inner instanceof Result) {
+                break;
+            }
+            $current = $inner;
+        }
+
+        return $current;
+    }
+
+    /**
+     * Builds a Result from a Go-style [value, error] tuple (the inverse of split()).
+     *
+     * A non-null error wins and produces an Err; otherwise the value is wrapped in Ok.
+     * [null, null] is ambiguous (it is produced by both Ok(null) and Err(null)): it becomes
+     * Ok(null) by default, and is rejected when $strict is true.
+     *
+     * @template T
+     * @template E
+     *
+     * @param array{T, E|null} $tuple
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if $strict is true and both elements are null
+     */
+    public static function fromTuple(array $tuple, bool $strict = false): Result
+    {
+        [$value, $error] = $tuple;
+        if ($error !== null) {
+            return new Err($error);
+        }
+        if ($strict && $value === null) {
+            throw new \InvalidArgumentException('cannot build a Result from an ambiguous [null, null] tuple');
+        }
+
+        return new Ok($value);
+    }
+
+    /**
+     * Lazily yields the inner Results of an Ok holding an iterable (e.g. a generator) of Results.
+     *
+     * If the outer Result is an Err, that Err is yielded once. If iterating the inner iterable
+     * throws, the Throwable is yielded as a final Err and iteration stops.
+     *
+     * @template T
+     * @template E1
+     * @template E2
+     *
+     * @param Result<iterable<Result<T, E2>>, E1> $result
+     *
+     * @return \Generator<int, Result<T, E1|E2|\Throwable>, mixed, void>
+     */
+    public static function flattenGenerator(Result $result): \Generator
+    {
+        if ($result->isErr()) {
+            yield $result;
+
+            return;
+        }
+
+        try {
+            foreach ($result->unwrap() as $inner) {
+                yield $inner;
+            }
+        } catch (\Throwable $e) {
+            yield new Err($e);
+        }
+    }
+
+    /**
+     * Lazily threads a state through a stream of Results.
+     *
+     * For each Ok, $fn($state, $value) returns [$newState, $emit]; $emit is yielded wrapped in Ok
+     * and $newState is passed to the next call. The first Err is yielded as-is as the last
+     * element, after the emissions of all preceding Oks, and nothing after it is consumed.
+     *
+     * @template T
+     * @template E
+     * @template S
+     * @template U
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param S $initialState
+     * @param callable(S, T): array{S, U} $fn
+     *
+     * @return \Generator<int, Result<U, E>, mixed, void>
+     */
+    public static function scan(iterable $results, mixed $initialState, callable $fn): \Generator
+    {
+        $state = $initialState;
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                yield $result;
+
+                return;
+            }
+            [$state, $emit] = $fn($state, $result->unwrap());
+
+            yield new Ok($emit);
+        }
+    }
+
+    /**
+     * Summarizes an array of settled Results into a single report.
+     *
+     * Returns the number of Oks and Errs along with the success values and the error values,
+     * each keyed by the key of the Result they came from.
+     *
+     * @template K of array-key
+     * @template T
+     * @template E
+     *
+     * @param array<K, Result<T, E>> $results
+     *
+     * @return array{ok: int, err: int, values: array<K, T>, errors: array<K, E>}
+     */
+    public static function fromSettledArray(array $results): array
+    {
+        $values = [];
+        $errors = [];
+        foreach ($results as $key => $result) {
+            if ($result->isOk()) {
+                $values[$key] = $result->unwrap();
+            } else {
+                $errors[$key] = $result->unwrapErr();
+            }
+        }
+
+        return [
+            'ok' => \count($values),
+            'err' => \count($errors),
+            'values' => $values,
+            'errors' => $errors,
+        ];
+    }
+
+    /**
+     * Runs a fallible operation, retrying on Err with exponential backoff.
+     *
+     * $op receives the 1-based attempt number. After a failed attempt n the sleeper is called with
+     * min(maxDelayMs, baseDelayMs * multiplier^(n-1)) milliseconds; with jitter enabled a random delay
+     * between 0 and that value is used instead ("full jitter"). There is no delay after the last
+     * attempt, whose Err is returned. The sleeper defaults to usleep() and can be replaced in tests.
+     *
+     * Config keys (all optional): maxAttempts (3), baseDelayMs (100), maxDelayMs (10000),
+     * multiplier (2.0), jitter (false).
+     *
+     * @template T
+     * @template E
+     *
+     * @param array{maxAttempts?: int, baseDelayMs?: int, maxDelayMs?: int, multiplier?: float, jitter?: bool} $config
+     * @param callable(int): Result<T, E> $op
+     * @param (callable(int): mixed)|null $sleeper called with the delay in milliseconds
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if maxAttempts is less than 1
+     */
+    public static function retryWith(array $config, callable $op, ?callable $sleeper = null): Result
+    {
+        $maxAttempts = $config['maxAttempts'] ?? 3;
+        $baseDelayMs = $config['baseDelayMs'] ?? 100;
+        $maxDelayMs = $config['maxDelayMs'] ?? 10000;
+        $multiplier = $config['multiplier'] ?? 2.0;
+        $jitter = $config['jitter'] ?? false;
+        if ($maxAttempts < 1) {
+            throw new \InvalidArgumentException(\sprintf('maxAttempts must be at least 1, %d given', $maxAttempts));
+        }
+        $sleeper ??= static fn (int $milliseconds) => usleep($milliseconds * 1000);
+
+        $attempt = 1;
+        while (true) {
+            $result = $op($attempt);
+            if ($result->isOk() || $attempt >= $maxAttempts) {
+                return $result;
+            }
+            $delay = (int) min($maxDelayMs, round($baseDelayMs * $multiplier ** ($attempt - 1)));
+            $sleeper($jitter ? random_int(0, max(0, $delay)) : $delay);
+            $attempt++;
+        }
+    }
+
+    /**
+     * Classifies an enum case as success or failure.
+     *
+     * Returns Ok($case) if the case is one of $okCases (compared by identity), otherwise Err($case).
+     * Works with both pure and backed enums.
+     *
+     * @template C of \UnitEnum
+     *
+     * @param C $case
+     * @param array<C> $okCases
+     *
+     * @return Result<C, C>
+     */
+    public static function fromEnum(\UnitEnum $case, array $okCases): Result
+    {
+        if (\in_array($case, $okCases, true)) {
+            return new Ok($case);
+        }
+
+        return new Err($case);
+    }
+
+    /**
+     * Groups items by a key computed by a fallible key function.
+     *
+     * Returns Ok with the items grouped under their keys (in insertion order within each group),
+     * or the first Err returned by $keyFn.
+     *
+     * @template V
+     * @template K of array-key
+     * @template E
+     *
+     * @param iterable<V> $items
+     * @param callable(V): Result<K, E> $keyFn
+     *
+     * @return Result<array<K, list<V>>, E>
+     */
+    public static function groupBy(iterable $items, callable $keyFn): Result
+    {
+        $groups = [];
+        foreach ($items as $item) {
+            $key = $keyFn($item);
+            if ($key->isErr()) {
+                return $key;
+            }
+            $groups[$key->unwrap()][] = $item;
+        }
+
+        return new Ok($groups);
+    }
+
+    /**
+     * Builds a Result from an Either-style tagged array (the inverse of toEither()).
+     *
+     * ['Right' => value] becomes Ok and ['Left' => error] becomes Err.
+     *
+     * @template T
+     * @template E
+     *
+     * @param array{Right: T}|array{Left: E} $either
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if the array does not hold exactly one of the Left / Right keys
+     */
+    public static function fromEither(array $either): Result
+    {
+        if (\count($either) === 1) {
+            if (\array_key_exists('Right', $either)) {
+                return new Ok($either['Right']);
+            }
+            if (\array_key_exists('Left', $either)) {
+                return new Err($either['Left']);
+            }
+        }
+
+        throw new \InvalidArgumentException('an Either array must hold exactly one of the "Left" or "Right" keys');
+    }
+
+    /**
+     * Normalizes a value that may or may not already be a Result.
+     *
+     * A Result is returned as-is, null becomes Err($errIfNull), and any other value is wrapped in Ok.
+     *
+     * @template T
+     * @template E
+     * @template F
+     *
+     * @param Result<T, E>|T|null $maybeResult
+     * @param F $errIfNull
+     *
+     * @return Result<T, E|F>
+     */
+    public static function normalize(mixed $maybeResult, mixed $errIfNull): Result
+    {
+        if ($maybeResult instanceof Result) {
+            return $maybeResult;
+        }
+        if ($maybeResult === null) {
+            return new Err($errIfNull);
+        }
+
+        return new Ok($maybeResult);
+    }
+
+    /**
+     * Creates a builder whose failure paths share one error factory.
+     *
+     * @template E
+     *
+     * @param callable(mixed): E $errorFactory receives the failure reason and returns the error value
+     *
+     * @return ResultBuilder<E>
+     */
+    public static function withErrorType(callable $errorFactory): ResultBuilder
+    {
+        return new ResultBuilder($errorFactory(...));
+    }
+
+    /**
+     * Returns the Result of the first operation to settle, whether Ok or Err.
+     *
+     * This library has no concurrency abstraction and PHP has no built-in way to run callables
+     * in parallel, so this is the sequential fallback: operations run in order, the first one
+     * therefore always settles first, and the remaining ones are never invoked (the closest
+     * equivalent of cancelling them). Racing real I/O needs an event loop, which is out of scope.
+     *
+     * @template T
+     * @template E
+     *
+     * @param array<callable(): Result<T, E>> $ops
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if $ops is empty
+     */
+    public static function race(array $ops): Result
+    {
+        foreach ($ops as $op) {
+            return $op();
+        }
+
+        throw new \InvalidArgumentException('Results::race() requires at least one operation');
+    }
+
+    /**
+     * Runs a fallible operation and measures how long it took.
+     *
+     * Returns [$result, $seconds], measured with the monotonic clock. If $op throws, the Throwable
+     * is wrapped in Err (as with try()) and the time until the throw is reported.
+     *
+     * @template T
+     * @template E
+     *
+     * @param callable(): Result<T, E> $op
+     *
+     * @return array{Result<T, E|\Throwable>, float}
+     */
+    public static function timed(callable $op): array
+    {
+        $start = hrtime(true);
+
+        try {
+            $result = $op();
+        } catch (\Throwable $e) {
+            $result = new Err($e);
+        }
+
+        return [$result, (hrtime(true) - $start) / 1e9];
+    }
+
+    /**
+     * Flattens several nested Results and combines their inner values.
+     *
+     * Each argument is a Result<Result<T, E2>, E1>. Returns Ok with the inner values in argument
+     * order, or the first Err found, whether it is an outer or an inner one.
+     *
+     * @template T
+     * @template E1
+     * @template E2
+     *
+     * @param Result<Result<T, E2>, E1> ...$results
+     *
+     * @return Result<list<T>, E1|E2>
      */
-    public static function flatten(Result $result): Result
+    public static function flattenMany(Result ...$results): Result
     {
-        return $result->andThen(static fn (Result $inner): Result => $inner);
+        return self::combine(array_map(self::flatten(...), $results));
+    }
+
+    /**
+     * Collects up to $n success values, then stops consuming the source.
+     *
+     * Returns Ok with the collected values (fewer than $n if the source ends first). An Err
+     * encountered before $n values are collected is returned, even if some values were already
+     * collected; an Err after the first $n values is never reached.
+     *
+     * @template T
+     * @template E
+     *
+     * @param iterable<Result<T, E>> $results
+     *
+     * @return Result<list<T>, E>
+     *
+     * @throws \InvalidArgumentException if $n is negative
+     */
+    public static function collectFirstN(iterable $results, int $n): Result
+    {
+        if ($n < 0) {
+            throw new \InvalidArgumentException(\sprintf('$n must not be negative, %d given', $n));
+        }
+
+        $values = [];
+        if ($n === 0) {
+            return new Ok($values);
+        }
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                return $result;
+            }
+            $values[] = $result->unwrap();
+            if (\count($values) === $n) {
+                break;
+            }
+        }
+
+        return new Ok($values);
+    }
+
+    /**
+     * Runs a generator-based computation, giving it a `?`-like early return.
+     *
+     * Protocol: the generator yields Results. The unwrapped value of each yielded Ok is sent back
+     * into the generator (it becomes the value of the yield expression); the first yielded Err is
+     * returned immediately and the generator is not resumed. When the generator returns, its
+     * return value is wrapped in Ok. Yielding anything other than a Result is a programming error.
+     *
+     * @template T
+     * @template E
+     *
+     * @param \Generator<mixed, Result<mixed, E>, mixed, T> $generator an already-constructed generator
+     *
+     * @return Result<T, E>
+     *
+     * @throws \UnexpectedValueException if the generator yields something other than a Result
+     */
+    public static function run(\Generator $generator): Result
+    {
+        $yielded = $generator->current();
+        while ($generator->valid()) {
+            if (!$yielded instanceof Result) {
+                throw new \UnexpectedValueException(\sprintf('Results::run() expects the generator to yield Results, %s yielded', get_debug_type($yielded)));
+            }
+            if ($yielded->isErr()) {
+                return $yielded;
+            }
+            $yielded = $generator->send($yielded->unwrap());
+        }
+
+        return new Ok($generator->getReturn());
+    }
+
+    /**
+     * Applies a fallible operation to every item and combines the outcomes.
+     *
+     * Returns Ok with the success values in item order. By default it fails fast, returning the
+     * first Err without applying $op to the remaining items; with $accumulate it applies $op to
+     * every item and returns Err with the list of all errors if any failed.
+     *
+     * @template V
+     * @template U
+     * @template E
+     *
+     * @param iterable<V> $items
+     * @param callable(V): Result<U, E> $op
+     *
+     * @return ($accumulate is true ? Result<list<U>, list<E>> : Result<list<U>, E>)
+     */
+    public static function batch(iterable $items, callable $op, bool $accumulate = false): Result
+    {
+        $values = [];
+        $errors = [];
+        foreach ($items as $item) {
+            $result = $op($item);
+            if ($result->isOk()) {
+                $values[] = $result->unwrap();
+            } elseif ($accumulate) {
+                $errors[] = $result->unwrapErr();
+            } else {
+                return $result;
+            }
+        }
+
+        return $errors === [] ? new Ok($values) : new Err($errors);
+    }
+
+    /**
+     * Folds the success values of a non-empty set of Results.
+     *
+     * The first success value is the initial accumulator and each following one is combined with
+     * $combine($acc, $next). Returns the first Err if any element fails. For an empty input there is
+     * nothing to fold: an \InvalidArgumentException is thrown by default, or Err($onEmpty()) is
+     * returned if $onEmpty is given.
+     *
+     * @template T
+     * @template E
+     * @template F
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param callable(T, T): T $combine
+     * @param (callable(): F)|null $onEmpty
+     *
+     * @return Result<T, E|F>
+     *
+     * @throws \InvalidArgumentException if $results is empty and $onEmpty is not given
+     */
+    public static function reduce(iterable $results, callable $combine, ?callable $onEmpty = null): Result
+    {
+        $values = [];
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                return $result;
+            }
+            $values[] = $result->unwrap();
+        }
+        if ($values === []) {
+            if ($onEmpty === null) {
+                throw new \InvalidArgumentException('Results::reduce() requires at least one Result');
+            }
+
+            return new Err($onEmpty());
+        }
+
+        $acc = array_shift($values);
+        foreach ($values as $value) {
+            $acc = $combine($acc, $value);
+        }
+
+        return new Ok($acc);
+    }
+
+    /**
+     * Compares two values with <=>, failing instead of silently juggling incompatible types.
+     *
+     * The values are comparable if both are numbers (int or float, but not NAN), both are strings,
+     * or both are bools. Anything else (mixed types such as 1 and '1', null, arrays, objects) is
+     * incomparable and yields Err($errIfIncomparable).
+     *
+     * @template F
+     *
+     * @param F $errIfIncomparable
+     *
+     * @return Result<int<-1, 1>, F>
+     */
+    public static function compareValues(mixed $a, mixed $b, mixed $errIfIncomparable): Result
+    {
+        $comparable = match (true) {
+            (\is_int($a) || \is_float($a)) && (\is_int($b) || \is_float($b)) => !is_nan((float) $a) && !is_nan((float) $b),
+            \is_string($a) && \is_string($b), \is_bool($a) && \is_bool($b) => true,
+            default => false,
+        };
+        if (!$comparable) {
+            return new Err($errIfIncomparable);
+        }
+
+        return new Ok($a <=> $b);
+    }
+
+    /**
+     * Safely reads a nested value from an array, such as decoded JSON.
+     *
+     * Walks $data following the keys in $path (string or int keys). Returns Ok with the value
+     * found at the end of the path (which may be null), or Err($errIfMissing) as soon as a key is
+     * absent or an intermediate value is not an array. An empty path yields the whole array.
+     *
+     * @template F
+     *
+     * @param array<mixed> $data
+     * @param list<array-key> $path
+     * @param F $errIfMissing
+     *
+     * @return Result<mixed, F>
+     */
+    public static function dig(array $data, array $path, mixed $errIfMissing): Result
+    {
+        $current = $data;
+        foreach ($path as $key) {
+            if (!\is_array($current) || !\array_key_exists($key, $current)) {
+                return new Err($errIfMissing);
+            }
+            $current = $current[$key];
+        }
+
+        return new Ok($current);
+    }
+
+    /**
+     * Invokes producers in order and returns the first Ok.
+     *
+     * Producers are lazy: once one returns Ok, the remaining ones are never invoked. If none
+     * succeeds, returns Err with the list of all errors in producer order (an empty list if no
+     * producers are given).
+     *
+     * @template T
+     * @template E
+     *
+     * @param callable(): Result<T, E> ...$producers
+     *
+     * @return Result<T, list<E>>
+     */
+    public static function firstOk(callable ...$producers): Result
+    {
+        $errors = [];
+        foreach ($producers as $producer) {
+            $result = $producer();
+            if ($result->isOk()) {
+                return $result;
+            }
+            $errors[] = $result->unwrapErr();
+        }
+
+        return new Err($errors);
+    }
+
+    /**
+     * Applies a function to the success values of any number of Results.
+     *
+     * If all are Ok, calls $fn with the values as positional arguments in order (keys are ignored)
+     * and returns Ok with its return value; otherwise returns the first Err.
+     *
+     * @template T
+     * @template E
+     * @template U
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param callable(T...): U $fn
+     *
+     * @return Result<U, E>
+     */
+    public static function mapAll(iterable $results, callable $fn): Result
+    {
+        return self::combine($results)->map(static fn (array $values): mixed => $fn(...$values));
+    }
+
+    /**
+     * Indexes items by a key computed by a fallible key function.
+     *
+     * Returns Ok with each item stored under its key, or the first Err returned by $keyFn.
+     * Duplicate keys are resolved last-wins by default; with $errorOnDuplicate the first
+     * duplicate instead yields an Err holding an \UnexpectedValueException naming the key.
+     *
+     * @template V
+     * @template K of array-key
+     * @template E
+     *
+     * @param iterable<V> $items
+     * @param callable(V): Result<K, E> $keyFn
+     *
+     * @return Result<array<K, V>, E|\UnexpectedValueException>
+     */
+    public static function indexBy(iterable $items, callable $keyFn, bool $errorOnDuplicate = false): Result
+    {
+        $indexed = [];
+        foreach ($items as $item) {
+            $key = $keyFn($item);
+            if ($key->isErr()) {
+                return $key;
+            }
+            $index = $key->unwrap();
+            if ($errorOnDuplicate && \array_key_exists($index, $indexed)) {
+                return new Err(new \UnexpectedValueException(\sprintf('duplicate key "%s"', $index)));
+            }
+            $indexed[$index] = $item;
+        }
+
+        return new Ok($indexed);
+    }
+
+    /**
+     * Describes every Result without short-circuiting (like JavaScript's Promise.allSettled).
+     *
+     * Each entry is ['ok' => true, 'value' => ...] or ['ok' => false, 'error' => ...], in input
+     * order and under the input key.
+     *
+     * @template K of array-key
+     * @template T
+     * @template E
+     *
+     * @param iterable<K, Result<T, E>> $results
+     *
+     * @return array<K, array{ok: true, value: T}|array{ok: false, error: E}>
+     */
+    public static function collectAllSettled(iterable $results): array
+    {
+        $settled = [];
+        foreach ($results as $key => $result) {
+            $settled[$key] = $result->isOk()
+                ? ['ok' => true, 'value' => $result->unwrap()]
+                : ['ok' => false, 'error' => $result->unwrapErr()];
+        }
+
+        return $settled;
+    }
+
+    /**
+     * Executes a prepared PDO statement and wraps the fetched rows in a Result.
+     *
+     * Respects the connection's error mode: under PDO::ERRMODE_EXCEPTION the thrown
+     * \PDOException is wrapped in Err; under the silent (or warning) mode a failed execute or
+     * fetch yields Err with the statement's errorInfo() array. On success returns Ok with the
+     * rows from fetchAll() using the statement's default fetch mode.
+     *
+     * @param array<int|string, mixed>|null $params
+     *
+     * @return Result<list<mixed>, \PDOException|array<int, mixed>>
+     */
+    public static function fromPdo(\PDOStatement $stmt, ?array $params = null): Result
+    {
+        try {
+            if (!$stmt->execute($params)) {
+                return new Err($stmt->errorInfo());
+            }
+            $rows = $stmt->fetchAll();
+        } catch (\PDOException $e) {
+            return new Err($e);
+        }
+
+        return new Ok($rows);
+    }
+
+    /**
+     * Wraps a fallible operation so its Results are cached per key derived from the arguments.
+     *
+     * Each call computes $keyFn(...$args); the first call for a key runs $op(...$args) and later
+     * calls with the same key return the cached Result (Ok or Err) without running $op again.
+     * The cache belongs to the returned wrapper, so separate wrappers never share entries.
+     *
+     * @template T
+     * @template E
+     *
+     * @param callable(mixed...): Result<T, E> $op
+     * @param callable(mixed...): array-key $keyFn
+     *
+     * @return \Closure(mixed...): Result<T, E>
+     */
+    public static function memoizeBy(callable $op, callable $keyFn): \Closure
+    {
+        /** @var array<array-key, Result<T, E>> $cache */
+        $cache = [];
+
+        return static function (mixed ...$args) use ($op, $keyFn, &$cache): Result {
+            $key = $keyFn(...$args);
+            if (!\array_key_exists($key, $cache)) {
+                $cache[$key] = $op(...$args);
+            }
+
+            return $cache[$key];
+        };
+    }
+
+    /**
+     * Reads an environment variable with getenv() and wraps it in a Result.
+     *
+     * Returns Ok with the value when the variable is set (an empty string counts as set), or
+     * Err when it is unset: $errIfMissing if given, otherwise a message naming the variable.
+     *
+     * @return Result<string, string>
+     */
+    public static function fromEnv(string $name, ?string $errIfMissing = null): Result
+    {
+        $value = getenv($name);
+        if ($value === false) {
+            return new Err($errIfMissing ?? \sprintf('environment variable "%s" is not set', $name));
+        }
+
+        return new Ok($value);
+    }
+
+    /**
+     * Like fromEnv(), but also treats an empty string as missing.
+     *
+     * Returns Err($errIfMissing) for an unset or empty variable; without $errIfMissing the default
+     * message distinguishes "is not set" from "is empty".
+     *
+     * @return Result<non-empty-string, string>
+     */
+    public static function fromEnvRequired(string $name, ?string $errIfMissing = null): Result
+    {
+        return self::fromEnv($name, $errIfMissing)->andThen(
+            static fn (string $value): Result => $value === ''
+                ? new Err($errIfMissing ?? \sprintf('environment variable "%s" is empty', $name))
+                : new Ok($value),
+        );
+    }
+
+    /**
+     * Builds an associative array from items with a fallible pair-producing function.
+     *
+     * $fn returns Ok([$key, $value]) or Err for each item. Returns Ok with every value stored
+     * under its key (a repeated key keeps the last value), or the first Err without applying $fn
+     * to the remaining items. An Ok that is not a [key, value] pair is a programming error.
+     *
+     * @template V
+     * @template K of array-key
+     * @template U
+     * @template E
+     *
+     * @param iterable<V> $items
+     * @param callable(V): Result<array{K, U}, E> $fn
+     *
+     * @return Result<array<K, U>, E>
+     *
+     * @throws \UnexpectedValueException if $fn returns an Ok that is not a [key, value] pair
+     */
+    public static function collectMap(iterable $items, callable $fn): Result
+    {
+        $map = [];
+        foreach ($items as $item) {
+            $result = $fn($item);
+            if ($result->isErr()) {
+                return $result;
+            }
+            $pair = $result->unwrap();
+            if (!\is_array($pair) || !array_is_list($pair) || \count($pair) !== 2 || !(\is_int($pair[0]) || \is_string($pair[0]))) {
+                throw new \UnexpectedValueException(\sprintf('Results::collectMap() expects Ok([key, value]) pairs, %s given', get_debug_type($pair)));
+            }
+            $map[$pair[0]] = $pair[1];
+        }
+
+        return new Ok($map);
+    }
+
+    /**
+     * Sets a global formatter for rendering error values, or restores the built-in rendering with null.
+     *
+     * The formatter is used wherever an Err's error value is turned into text: the messages of
+     * the UnwrapException thrown by unwrap(), expect() and expectType() on an Err, the default
+     * formatter of tee() on an Err, the error of toResponse() and the error message recorded by
+     * span(). UnwrapException messages are still kept on a single line and truncated.
+     *
+     * @param (callable(mixed): string)|null $formatter
+     */
+    public static function setErrorFormatter(?callable $formatter): void
+    {
+        self::$errorFormatter = $formatter === null ? null : $formatter(...);
+    }
+
+    /**
+     * Renders an error value with the formatter set by setErrorFormatter(), or returns null if none is set.
+     *
+     * @internal
+     */
+    public static function formatError(mixed $error): ?string
+    {
+        return self::$errorFormatter === null ? null : (self::$errorFormatter)($error);
+    }
+
+    /**
+     * Lazily demultiplexes a stream of Results into a generator of success values and a generator of error values.
+     *
+     * Returns [$oks, $errs]. The source is iterated at most once, and only as far as a consumer
+     * pulls: advancing one generator reads from the source until it finds a value for its side,
+     * buffering the values of the other side for the other generator. Consuming only one side of
+     * a long stream therefore buffers the whole other side in memory. Neither generator can be
+     * rewound, and the source must not be iterated elsewhere while they are in use.
+     *
+     * @template T
+     * @template E
+     *
+     * @param iterable<Result<T, E>> $results
+     *
+     * @return array{\Generator<int, T, mixed, void>, \Generator<int, E, mixed, void>}
+     */
+    public static function splitGenerators(iterable $results): array
+    {
+        $source = (static fn (): \Generator => yield from $results)();
+        $started = false;
+        $pull = static function () use ($source, &$started): ?Result {
+            if ($started) {
+                $source->next();
+            }
+            $started = true;
+
+            return $source->valid() ? $source->current() : null;
+        };
+        /** @var \SplQueue<T> $okValues */
+        $okValues = new \SplQueue();
+        /** @var \SplQueue<E> $errValues */
+        $errValues = new \SplQueue();
+
+        return [
+            self::demultiplex($pull, $okValues, $okValues, $errValues),
+            self::demultiplex($pull, $errValues, $okValues, $errValues),
+        ];
+    }
+
+    /**
+     * Yields the values queued in $wanted, pulling Results from the shared source into both queues on demand.
+     *
+     * @template T
+     * @template E
+     * @template V
+     *
+     * @param \Closure(): (Result<T, E>|null) $pull returns the next Result of the source, or null when it is exhausted
+     * @param \SplQueue<V> $wanted either $okValues or $errValues
+     * @param \SplQueue<T> $okValues
+     * @param \SplQueue<E> $errValues
+     *
+     * @return \Generator<int, V, mixed, void>
+     */
+    private static function demultiplex(\Closure $pull, \SplQueue $wanted, \SplQueue $okValues, \SplQueue $errValues): \Generator
+    {
+        while (true) {
+            if (!$wanted->isEmpty()) {
+                yield $wanted->dequeue();
+
+                continue;
+            }
+            $result = $pull();
+            if ($result === null) {
+                return;
+            }
+            if ($result->isOk()) {
+                $okValues->enqueue($result->unwrap());
+            } else {
+                $errValues->enqueue($result->unwrapErr());
+            }
+        }
+    }
+
+    /**
+     * Returns the first Ok argument, or the last Err if every argument is an Err.
+     *
+     * Unlike firstOk(), which collects every error, only the error of the final fallback
+     * surfaces; the earlier errors are discarded.
+     *
+     * @template T
+     * @template E
+     *
+     * @param Result<T, E> ...$results
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if no Result is given
+     */
+    public static function coalesce(Result ...$results): Result
+    {
+        if ($results === []) {
+            throw new \InvalidArgumentException('Results::coalesce() requires at least one Result');
+        }
+        foreach ($results as $result) {
+            if ($result->isOk()) {
+                return $result;
+            }
+        }
+
+        return $result;
+    }
+
+    /**
+     * Returns the success values, dropping the Errs.
+     *
+     * Values are returned as a list in input order. If given, $onDropped is called with each
+     * dropped error (e.g. for logging) in the order it is encountered.
+     *
+     * @template T
+     * @template E
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param (callable(E): mixed)|null $onDropped
+     *
+     * @return list<T>
+     */
+    public static function collectOkOnly(iterable $results, ?callable $onDropped = null): array
+    {
+        $values = [];
+        foreach ($results as $result) {
+            if ($result->isOk()) {
+                $values[] = $result->unwrap();
+            } elseif ($onDropped !== null) {
+                $onDropped($result->unwrapErr());
+            }
+        }
+
+        return $values;
+    }
+
+    /**
+     * Like combine(), but also catches a Throwable raised while iterating.
+     *
+     * Returns Ok with the list of values if iteration completes without an Err, the first Err
+     * otherwise, or Err with the Throwable if the iterable (e.g. a generator) throws between yields.
+     *
+     * @template T
+     * @template E
+     *
+     * @param iterable<Result<T, E>> $results
+     *
+     * @return Result<list<T>, E|\Throwable>
+     */
+    public static function collectTry(iterable $results): Result
+    {
+        try {
+            return self::combine($results);
+        } catch (\Throwable $e) {
+            return new Err($e);
+        }
+    }
+
+    /**
+     * Like combine(), but the Err identifies which element failed.
+     *
+     * Returns Ok with the list of values, or Err(['index' => i, 'error' => e]) for the first
+     * failure, where i is the zero-based position of the element in iteration order (keys are
+     * ignored, so positions are counted correctly for generators too).
+     *
+     * @template T
+     * @template E
+     *
+     * @param iterable<Result<T, E>> $results
+     *
+     * @return Result<list<T>, array{index: int, error: E}>
+     */
+    public static function collectIndexed(iterable $results): Result
+    {
+        $values = [];
+        $index = 0;
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                return new Err(['index' => $index, 'error' => $result->unwrapErr()]);
+            }
+            $values[] = $result->unwrap();
+            ++$index;
+        }
+
+        return new Ok($values);
+    }
+
+    /**
+     * Returns Ok($value) if the predicate holds for the value, otherwise Err($err).
+     *
+     * @template T
+     * @template F
+     *
+     * @param T $value
+     * @param callable(T): bool $predicate
+     * @param F $err
+     *
+     * @return Result<T, F>
+     */
+    public static function okWhen(mixed $value, callable $predicate, mixed $err): Result
+    {
+        return $predicate($value) ? new Ok($value) : new Err($err);
+    }
+
+    /**
+     * Like okWhen(), but the error is computed from the value only when the predicate fails.
+     *
+     * @template T
+     * @template F
+     *
+     * @param T $value
+     * @param callable(T): bool $predicate
+     * @param callable(T): F $errFn
+     *
+     * @return Result<T, F>
+     */
+    public static function okWhenWith(mixed $value, callable $predicate, callable $errFn): Result
+    {
+        return $predicate($value) ? new Ok($value) : new Err($errFn($value));
+    }
+
+    /**
+     * Treats a count (e.g. affected rows) below a minimum as a failure.
+     *
+     * Returns Ok($count) if $count is at least $minimum (1 by default, so only 0 fails), otherwise Err($errIfBelow).
+     *
+     * @template F
+     *
+     * @param F $errIfBelow
+     *
+     * @return Result<int, F>
+     */
+    public static function fromCount(int $count, mixed $errIfBelow, int $minimum = 1): Result
+    {
+        return $count >= $minimum ? new Ok($count) : new Err($errIfBelow);
+    }
+
+    /**
+     * Reduces only the errors into a custom accumulated error.
+     *
+     * Every Result is visited. If there are no errors, returns Ok with the list of values and the
+     * reducer is never called; otherwise returns Err with $reducer($acc, $error) folded over the
+     * errors in order, starting from $initial.
+     *
+     * @template T
+     * @template E
+     * @template A
+     *
+     * @param iterable<Result<T, E>> $results
+     * @param A $initial
+     * @param callable(A, E): A $reducer
+     *
+     * @return Result<list<T>, A>
+     */
+    public static function foldErrors(iterable $results, mixed $initial, callable $reducer): Result
+    {
+        $values = [];
+        $acc = $initial;
+        $failed = false;
+        foreach ($results as $result) {
+            if ($result->isOk()) {
+                $values[] = $result->unwrap();
+
+                continue;
+            }
+            $acc = $reducer($acc, $result->unwrapErr());
+            $failed = true;
+        }
+
+        return $failed ? new Err($acc) : new Ok($values);
+    }
+
+    /**
+     * Runs every validator against a value and collects all failures (applicative validation).
+     *
+     * Each validator returns a Result; their success values are ignored. Returns Ok($value) if
+     * every validator passes, otherwise Err with the list of errors in validator order. All
+     * validators run, even after a failure.
+     *
+     * @template T
+     * @template F
+     *
+     * @param T $value
+     * @param callable(T): Result<mixed, F> ...$validators
+     *
+     * @return Result<T, list<F>>
+     */
+    public static function validateAll(mixed $value, callable ...$validators): Result
+    {
+        $failures = [];
+        foreach ($validators as $validator) {
+            $result = $validator($value);
+            if ($result->isErr()) {
+                $failures[] = $result->unwrapErr();
+            }
+        }
+
+        return $failures === [] ? new Ok($value) : new Err($failures);
+    }
+
+    /**
+     * Runs an operation only if the value changed, to avoid redundant side effects.
+     *
+     * Returns $op($new) if $old and $new differ, otherwise Err($errNoChange) without calling $op.
+     * By default they are compared with ===, which compares arrays deeply but objects by identity;
+     * pass $equals($old, $new) to compare value objects or to loosen the comparison.
+     *
+     * @template V
+     * @template T
+     * @template E
+     * @template F
+     *
+     * @param V $old
+     * @param V $new
+     * @param callable(V): Result<T, E> $op
+     * @param F $errNoChange
+     * @param (callable(V, V): bool)|null $equals
+     *
+     * @return Result<T, E|F>
+     */
+    public static function onlyIfChanged(mixed $old, mixed $new, callable $op, mixed $errNoChange, ?callable $equals = null): Result
+    {
+        $unchanged = $equals !== null ? $equals($old, $new) : $old === $new;
+
+        return $unchanged ? new Err($errNoChange) : $op($new);
+    }
+
+    /**
+     * Writes each Result as one JSON line (NDJSON) to a stream, flushing after every line.
+     *
+     * Each line uses the tagged shape {"ok": value} or {"err": error}. Returns Ok with the number
+     * of lines written, or stops at the first failure and returns Err: a \JsonException if a
+     * payload cannot be encoded, or a \RuntimeException (stating how many lines were written) if
+     * writing to the stream fails.
+     *
+     * @param iterable<Result<mixed, mixed>> $results
+     * @param resource $stream a writable stream
+     *
+     * @return Result<int, \JsonException|\RuntimeException>
+     */
+    public static function toNdjson(iterable $results, mixed $stream): Result
+    {
+        $written = 0;
+        foreach ($results as $result) {
+            try {
+                $line = json_encode($result->isOk() ? ['ok' => $result->unwrap()] : ['err' => $result->unwrapErr()], \JSON_THROW_ON_ERROR);
+            } catch (\JsonException $e) {
+                return new Err($e);
+            }
+            if (fwrite($stream, $line . "\n") === false || !fflush($stream)) {
+                return new Err(new \RuntimeException(\sprintf('failed to write an NDJSON line after %d line(s)', $written)));
+            }
+            ++$written;
+        }
+
+        return new Ok($written);
+    }
+
+    /**
+     * Runs an operation repeatedly until a predicate accepts its Result.
+     *
+     * $op receives the 1-based attempt number. After each attempt $stop($result, $attempt) is
+     * called; returning true accepts that Result (Ok or Err). $maxAttempts is a hard cap: if
+     * $stop never accepts, the Result of the last attempt is returned as-is. There is no delay
+     * between attempts; use retryWith() for backoff.
+     *
+     * @template T
+     * @template E
+     *
+     * @param callable(int): Result<T, E> $op
+     * @param callable(Result<T, E>, int): bool $stop
+     *
+     * @return Result<T, E>
+     *
+     * @throws \InvalidArgumentException if $maxAttempts is less than 1
+     */
+    public static function retryUntil(callable $op, callable $stop, int $maxAttempts): Result
+    {
+        if ($maxAttempts < 1) {
+            throw new \InvalidArgumentException(\sprintf('maxAttempts must be at least 1, %d given', $maxAttempts));
+        }
+
+        $attempt = 1;
+        while (true) {
+            $result = $op($attempt);
+            if ($stop($result, $attempt) || $attempt >= $maxAttempts) {
+                return $result;
+            }
+            $attempt++;
+        }
+    }
+
+    /**
+     * Runs an operation inside a tracing span.
+     *
+     * Starts a span named $name, runs $op and tags the span with "result" => "ok" or "err". On
+     * Err the error message is recorded: the setErrorFormatter() formatter if set, otherwise the
+     * message of a \Throwable, a scalar or Stringable cast to string, or the type name. If $op
+     * throws, the span is tagged "err", the exception's message is recorded and it is rethrown.
+     * The span is always finished before returning.
+     *
+     * @template T
+     * @template E
+     *
+     * @param callable(): Result<T, E> $op
+     *
+     * @return Result<T, E>
+     */
+    public static function span(string $name, callable $op, Tracer $tracer): Result
+    {
+        $span = $tracer->startSpan($name);
+        try {
+            $result = $op();
+            $span->setTag('result', $result->isOk() ? 'ok' : 'err');
+            if ($result->isErr()) {
+                $error = $result->unwrapErr();
+                $span->recordError(self::formatError($error) ?? match (true) {
+                    $error instanceof \Throwable => $error->getMessage(),
+                    \is_scalar($error), $error instanceof \Stringable => (string) $error,
+                    default => get_debug_type($error),
+                });
+            }
+
+            return $result;
+        } catch (\Throwable $e) {
+            $span->setTag('result', 'err');
+            $span->recordError($e->getMessage());
+
+            throw $e;
+        } finally {
+            $span->finish();
+        }
+    }
+
+    /**
+     * Like try(), but converts only the listed exception types, each with its own error factory.
+     *
+     * $map maps exception class (or interface) names to factories. A thrown exception matching
+     * a key, including through a subclass, becomes Err($factory($exception)); when several keys
+     * match, the most specific class wins. Exceptions that match no key are rethrown.
+     *
+     * @template T
+     * @template F
+     *
+     * @param callable(): T $op
+     * @param array<class-string<\Throwable>, callable(\Throwable): F> $map
+     *
+     * @return Result<T, F>
+     *
+     * @throws \Throwable if $op throws an exception that matches no key in $map
+     */
+    public static function catchAs(callable $op, array $map): Result
+    {
+        try {
+            return new Ok($op());
+        } catch (\Throwable $e) {
+            $best = null;
+            foreach (array_keys($map) as $class) {
+                if ($e instanceof $class && ($best === null || is_a($class, $best, true))) {
+                    $best = $class;
+                }
+            }
+            if ($best === null) {
+                throw $e;
+            }
+
+            return new Err($map[$best]($e));
+        }
+    }
+
+    /**
+     * Returns the success values before the first Err, discarding that Err and everything after it.
+     *
+     * Unlike combine(), a failure does not fail the whole: the good prefix is the output. Iteration
+     * stops at the first Err, so the rest of a generator is never consumed.
+     *
+     * @template T
+     *
+     * @param iterable<Result<T, mixed>> $results
+     *
+     * @return list<T>
+     */
+    public static function takeUntilErr(iterable $results): array
+    {
+        $values = [];
+        foreach ($results as $result) {
+            if ($result->isErr()) {
+                break;
+            }
+            $values[] = $result->unwrap();
+        }
+
+        return $values;
     }
 }
@@ -0,0 +1,26 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * A span started by a Tracer.
+ */
+interface Span
+{
+    /**
+     * Sets a tag (attribute) on the span.
+     */
+    public function setTag(string $key, string|int|float|bool $value): void;
+
+    /**
+     * Records an error message on the span.
+     */
+    public function recordError(string $message): void;
+
+    /**
+     * Ends the span.
+     */
+    public function finish(): void;
+}
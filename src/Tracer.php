@@ -0,0 +1,16 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * A minimal tracer used by Results::span(), to be adapted to OpenTelemetry or another tracing library.
+ */
+interface Tracer
+{
+    /**
+     * Starts a span with the given name.
+     */
+    public function startSpan(string $name): Span;
+}
@@ -0,0 +1,69 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result;
+
+/**
+ * Runtime checks of values against type names given as strings.
+ *
+ * @internal
+ */
+final class TypeCheck
+{
+    /**
+     * Prevents instantiation since this is a static helper.
+     *
+     * @codeCoverageIgnore
+     */
+    private function __construct()
+    {
+    }
+
+    /**
+     * Returns true if the value matches the type.
+     *
+     * The type is a scalar/pseudo type name (int, float, string, bool, array, null, ...) or a
+     * class/interface name. Union syntax ("int|string") and the nullable shorthand ("?int") are supported.
+     */
+    public static function matches(mixed $value, string $type): bool
+    {
+        $type = trim($type);
+        if (str_starts_with($type, '?')) {
+            $type = 'null|' . substr($type, 1);
+        }
+
+        foreach (explode('|', $type) as $member) {
+            if (self::matchesSingle($value, trim($member))) {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    /**
+     * Checks a single (non-union) type name.
+     */
+    private static function matchesSingle(mixed $value, string $type): bool
+    {
+        $type = ltrim($type, '\\');
+
+        return match (strtolower($type)) {
+            'mixed' => true,
+            'null' => $value === null,
+            'bool' => \is_bool($value),
+            'true' => $value === true,
+            'false' => $value === false,
+            'int' => \is_int($value),
+            'float' => \is_float($value),
+            'string' => \is_string($value),
+            'array' => \is_array($value),
+            'object' => \is_object($value),
+            'iterable' => is_iterable($value),
+            'callable' => \is_callable($value),
+            'resource' => \is_resource($value),
+            default => $value instanceof $type,
+        };
+    }
+}
@@ -11,6 +11,9 @@ namespace Valbeat\Result;
  * The message contains a summary of the held value (analogous to Rust's panic message).
  * Note: scalar values appear in the message verbatim (after truncation), so be careful
  * about where you log if you put sensitive strings in the error value.
+ *
+ * getFile() / getLine() report the call site of unwrap() / expect() etc. in the calling code
+ * rather than the location inside this library where the exception is created.
  */
 final class UnwrapException extends \LogicException
 {
@@ -19,12 +22,18 @@ final class UnwrapException extends \LogicException
      */
     private const int MAX_SUMMARY_LENGTH = 120;
 
+    /**
+     * The number of stack frames searched for the caller's location; the library never nests its
+     * own calls deeper than this before throwing.
+     */
+    private const int MAX_BACKTRACE_FRAMES = 8;
+
     /**
      * Creates the exception for when unwrap() is called on an Err.
      */
     public static function unwrapOnErr(mixed $error): self
     {
-        return new self(\sprintf('called Result::unwrap() on an Err value: %s', self::describe($error)));
+        return self::create(\sprintf('called Result::unwrap() on an Err value: %s', self::describeError($error)));
     }
 
     /**
@@ -32,7 +41,7 @@ final class UnwrapException extends \LogicException
      */
     public static function unwrapErrOnOk(mixed $value): self
     {
-        return new self(\sprintf('called Result::unwrapErr() on an Ok value: %s', self::describe($value)));
+        return self::create(\sprintf('called Result::unwrapErr() on an Ok value: %s', self::describe($value)));
     }
 
     /**
@@ -40,25 +49,80 @@ final class UnwrapException extends \LogicException
      */
     public static function withMessage(string $message, mixed $value): self
     {
-        return new self(\sprintf('%s: %s', $message, self::describe($value)));
+        return self::create(\sprintf('%s: %s', $message, self::describe($value)));
     }
 
     /**
-     * Builds a value summary for the exception message.
+     * Creates the exception for expect() / expectType() on an Err from the caller's message and an error summary.
+     */
+    public static function withErrorMessage(string $message, mixed $error): self
+    {
+        return self::create(\sprintf('%s: %s', $message, self::describeError($error)));
+    }
+
+    /**
+     * Creates the exception for when intoNullableStrict() is called on an Ok(null).
+     */
+    public static function nullOnOk(): self
+    {
+        return self::create('called Result::intoNullableStrict() on an Ok(null) value, which is indistinguishable from an Err');
+    }
+
+    /**
+     * Creates the exception with its file/line pointing at the first stack frame outside this library.
      *
-     * The summary is normalized to a single line and truncated beyond MAX_SUMMARY_LENGTH.
+     * Only the innermost MAX_BACKTRACE_FRAMES frames are captured, so deep call stacks are not walked;
+     * if none of them is outside this library, the location where the exception was created is kept.
+     */
+    private static function create(string $message): self
+    {
+        $exception = new self($message);
+        foreach (debug_backtrace(\DEBUG_BACKTRACE_IGNORE_ARGS, self::MAX_BACKTRACE_FRAMES) as $frame) {
+            if (isset($frame['file'], $frame['line']) && !str_starts_with($frame['file'], __DIR__ . \DIRECTORY_SEPARATOR)) {
+                $exception->file = $frame['file'];
+                $exception->line = $frame['line'];
+                break;
+            }
+        }
+
+        return $exception;
+    }
+
+    /**
+     * Builds an error summary, using the formatter set by Results::setErrorFormatter() if there is one.
+     * To avoid replacing this exception when the formatter throws, it falls back to the built-in summary.
+     */
+    private static function describeError(mixed $error): string
+    {
+        try {
+            $formatted = Results::formatError($error);
+        } catch (\Throwable) {
+            return self::describe($error);
+        }
+
+        return $formatted === null ? self::describe($error) : self::normalize($formatted);
+    }
+
+    /**
+     * Builds a value summary for the exception message.
      */
     private static function describe(mixed $value): string
     {
-        $summary = match (true) {
+        return self::normalize(match (true) {
             $value instanceof \Throwable => \sprintf('%s: %s', self::className($value), $value->getMessage()),
             $value instanceof \UnitEnum => \sprintf('%s::%s', $value::class, $value->name),
             $value instanceof \Stringable => self::describeStringable($value),
             \is_object($value) => self::className($value),
             \is_scalar($value), null === $value => var_export($value, true),
             default => get_debug_type($value),
-        };
+        });
+    }
 
+    /**
+     * Normalizes a summary to a single line and truncates it beyond MAX_SUMMARY_LENGTH.
+     */
+    private static function normalize(string $summary): string
+    {
         $summary = str_replace(["\r\n", "\r", "\n"], '\n', $summary);
         if (\strlen($summary) > self::MAX_SUMMARY_LENGTH) {
             // Use mb_strcut to cut at a character boundary while respecting the byte limit
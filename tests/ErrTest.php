@@ -7,11 +7,20 @@ namespace Valbeat\Result\Tests;
 use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
 use Valbeat\Result\Err;
+use Valbeat\Result\HttpException;
+use Valbeat\Result\LayeredError;
 use Valbeat\Result\Ok;
+use Valbeat\Result\Result;
+use Valbeat\Result\Results;
 use Valbeat\Result\UnwrapException;
 
 class ErrTest extends TestCase
 {
+    protected function tearDown(): void
+    {
+        Results::setErrorFormatter(null);
+    }
+
     #[Test]
     public function isOk_returns_false(): void
     {
@@ -172,6 +181,36 @@ class ErrTest extends TestCase
         $err->expect('config file should be readable');
     }
 
+    #[Test]
+    public function unwrap_reportsCallerFileAndLine(): void
+    {
+        $err = self::asResult(new Err('error'));
+        $line = __LINE__ + 3;
+
+        try {
+            $err->unwrap();
+            $this->fail('UnwrapException was not thrown');
+        } catch (UnwrapException $e) {
+            $this->assertSame(__FILE__, $e->getFile());
+            $this->assertSame($line, $e->getLine());
+        }
+    }
+
+    #[Test]
+    public function expect_reportsCallerFileAndLine(): void
+    {
+        $err = self::asResult(new Err('error'));
+        $line = __LINE__ + 3;
+
+        try {
+            $err->expect('should have a value');
+            $this->fail('UnwrapException was not thrown');
+        } catch (UnwrapException $e) {
+            $this->assertSame(__FILE__, $e->getFile());
+            $this->assertSame($line, $e->getLine());
+        }
+    }
+
     #[Test]
     public function unwrapErr_returns_error_value(): void
     {
@@ -318,6 +357,36 @@ class ErrTest extends TestCase
         $this->assertSame(8, $result->unwrap());
     }
 
+    #[Test]
+    public function then_does_not_call_function(): void
+    {
+        $err = new Err('error');
+        $called = false;
+        $result = $err->then(function () use (&$called) {
+            $called = true;
+        });
+        $this->assertFalse($called);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function catch_whenCallbackReturnsValue_recovers_into_ok(): void
+    {
+        $err = new Err(self::asString('error'));
+        $result = $err->catch(fn ($e) => \strlen($e));
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(5, $result->unwrap());
+    }
+
+    #[Test]
+    public function catch_whenCallbackReturnsResult_returns_it_as_is(): void
+    {
+        $err = new Err('error');
+        $other = new Err('still failing');
+        $result = $err->catch(fn ($e) => $other);
+        $this->assertSame($other, $result);
+    }
+
     #[Test]
     public function match_calls_err_function(): void
     {
@@ -378,6 +447,531 @@ class ErrTest extends TestCase
         $this->assertSame('Final: [INITIAL ERROR]', $result->unwrapErr());
     }
 
+    #[Test]
+    public function split_returns_null_and_error(): void
+    {
+        $err = new Err('error');
+        [$value, $error] = $err->split();
+        $this->assertNull($value);
+        $this->assertSame('error', $error);
+    }
+
+    #[Test]
+    public function mapValues_and_mapKeys_do_not_apply_function(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->mapValues(fn ($x) => $x));
+        $this->assertSame($err, $err->mapKeys(fn ($key) => $key));
+    }
+
+    #[Test]
+    public function expectType_throwsUnwrapException_withErrorValueInMessage(): void
+    {
+        $err = new Err('error');
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage("called Result::expectType() on an Err value: 'error'");
+        $err->expectType('string');
+    }
+
+    #[Test]
+    public function tee_writes_error_to_stream_and_returns_self(): void
+    {
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        $err = new Err('disk full');
+        $result = $err->tee($stream, fn ($error) => "error: $error");
+        rewind($stream);
+        $this->assertSame($err, $result);
+        $this->assertSame('error: disk full' . \PHP_EOL, stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function toEither_returns_left_with_error(): void
+    {
+        $this->assertSame(['Left' => 'error'], (new Err('error'))->toEither());
+    }
+
+    #[Test]
+    public function expectShape_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->expectShape(['id']));
+    }
+
+    #[Test]
+    public function pluck_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->pluck('id'));
+    }
+
+    #[Test]
+    public function when_and_whenWith_return_self_without_calling_functions(): void
+    {
+        $err = new Err('error');
+        $called = false;
+        $fn = function () use (&$called) {
+            $called = true;
+
+            return new Ok(1);
+        };
+        $this->assertSame($err, $err->when(true, $fn));
+        $this->assertSame($err, $err->whenWith(fn () => true, $fn));
+        $this->assertFalse($called);
+    }
+
+    #[Test]
+    public function ensureNotEmpty_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->ensureNotEmpty('empty'));
+    }
+
+    #[Test]
+    public function toStringOr_returns_fallback(): void
+    {
+        $this->assertSame('n/a', (new Err('error'))->toStringOr('n/a'));
+    }
+
+    #[Test]
+    public function intoNullable_returns_null(): void
+    {
+        $err = new Err('error');
+        $this->assertNull($err->intoNullable());
+        $this->assertNull($err->intoNullableStrict());
+    }
+
+    #[Test]
+    public function tapOn_calls_err_handler_and_returns_self(): void
+    {
+        $err = new Err('error');
+        $calls = [];
+        $result = $err->tapOn([
+            'ok' => function ($value) use (&$calls) {
+                $calls[] = 'ok';
+            },
+            'err' => function ($error) use (&$calls) {
+                $calls[] = "err: $error";
+            },
+        ]);
+        $this->assertSame($err, $result);
+        $this->assertSame(['err: error'], $calls);
+    }
+
+    #[Test]
+    public function tapOn_withOnlyErrHandler_calls_it(): void
+    {
+        $err = new Err('error');
+        $captured = null;
+        $err->tapOn(['err' => function ($error) use (&$captured) {
+            $captured = $error;
+        }]);
+        $this->assertSame('error', $captured);
+    }
+
+    #[Test]
+    public function expectOne_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->expectOne('not exactly one'));
+    }
+
+    #[Test]
+    public function orThrowHttp_throws_http_exception_with_status_and_error(): void
+    {
+        $err = self::asResult(new Err(['field' => 'email']));
+
+        try {
+            $err->orThrowHttp(422, 'Validation failed');
+            $this->fail('HttpException was not thrown');
+        } catch (HttpException $e) {
+            $this->assertSame(422, $e->getStatusCode());
+            $this->assertSame(['field' => 'email'], $e->getError());
+            $this->assertSame('Validation failed', $e->getMessage());
+        }
+    }
+
+    #[Test]
+    public function orThrowHttp_withThrowableError_defaultsMessageAndChainsPrevious(): void
+    {
+        $cause = new \RuntimeException('user not found');
+        $err = self::asResult(new Err($cause));
+
+        try {
+            $err->orThrowHttp(404);
+            $this->fail('HttpException was not thrown');
+        } catch (HttpException $e) {
+            $this->assertSame('HTTP 404', $e->getMessage());
+            $this->assertSame($cause, $e->getPrevious());
+        }
+    }
+
+    #[Test]
+    public function cast_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->cast('int'));
+    }
+
+    #[Test]
+    public function isNested_whenErrorIsResult_returns_true(): void
+    {
+        $this->assertTrue((new Err(new Err('error')))->isNested());
+    }
+
+    #[Test]
+    public function isNested_whenErrorIsNotResult_returns_false(): void
+    {
+        $this->assertFalse((new Err('error'))->isNested());
+    }
+
+    #[Test]
+    public function withMetadata_is_propagated_through_mapErr_and_orElse(): void
+    {
+        $result = (new Err('error'))
+            ->withMetadata('traceId', 'abc')
+            ->mapErr(fn (string $e): string => strtoupper($e))
+            ->orElse(fn (string $e): Result => new Ok($e));
+        $this->assertSame('ERROR', $result->unwrap());
+        $this->assertSame('abc', $result->metadata('traceId'));
+    }
+
+    #[Test]
+    public function withMetadata_does_not_affect_equality(): void
+    {
+        $this->assertEquals(new Err('error'), (new Err('error'))->withMetadata('traceId', 'abc'));
+    }
+
+    #[Test]
+    public function unwrap_withCustomErrorFormatter_usesItInMessage(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => \is_array($error) ? (string) json_encode($error) : get_debug_type($error));
+        $err = new Err(['code' => 500]);
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage('called Result::unwrap() on an Err value: {"code":500}');
+        $err->unwrap();
+    }
+
+    #[Test]
+    public function expect_withCustomErrorFormatter_usesItInMessage(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => $error instanceof \Throwable ? $error->getMessage() : 'unknown');
+        $err = new Err(new \RuntimeException('boom'));
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage('config file should be readable: boom');
+        $err->expect('config file should be readable');
+    }
+
+    #[Test]
+    public function tee_withCustomErrorFormatter_usesItByDefault(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => \is_array($error) ? (string) json_encode($error) : 'unknown');
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        (new Err(['code' => 500]))->tee($stream);
+        rewind($stream);
+        $this->assertSame('{"code":500}' . \PHP_EOL, stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function toString_withCustomErrorFormatter_usesIt(): void
+    {
+        $this->assertSame('Err(boom)', (string) new Err(new \RuntimeException('boom')));
+        Results::setErrorFormatter(fn (mixed $error): string => \is_array($error) ? (string) json_encode($error) : 'unknown');
+        $this->assertSame('Err({"code":500})', (string) new Err(['code' => 500]));
+    }
+
+    #[Test]
+    public function unwrap_withThrowingErrorFormatter_fallsBackToBuiltInMessage(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => throw new \RuntimeException('formatter failed'));
+        $err = new Err('error');
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage("called Result::unwrap() on an Err value: 'error'");
+        $err->unwrap();
+    }
+
+    #[Test]
+    public function setErrorFormatter_withNull_restoresBuiltInRendering(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => 'custom');
+        Results::setErrorFormatter(null);
+        $err = new Err('error');
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage("called Result::unwrap() on an Err value: 'error'");
+        $err->unwrap();
+    }
+
+    #[Test]
+    public function mapErrChain_builds_ordered_layers_around_root_cause(): void
+    {
+        $root = new \RuntimeException('file not found');
+        $error = (new Err($root))
+            ->mapErrChain('reading config.json')
+            ->mapErrChain('loading config')
+            ->mapErrChain('starting app')
+            ->unwrapErr();
+        $this->assertInstanceOf(LayeredError::class, $error);
+        $this->assertSame('starting app > loading config > reading config.json: file not found', $error->format());
+        $this->assertSame($error->format(), (string) $error);
+        $this->assertSame(['reading config.json', 'loading config', 'starting app'], $error->getLayers());
+        $this->assertSame($root, $error->getRoot());
+    }
+
+    #[Test]
+    public function mapErrChain_formats_root_with_custom_formatter(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => \is_array($error) ? (string) json_encode($error) : 'unknown');
+        $error = (new Err(['code' => 404]))->mapErrChain('loading user')->unwrapErr();
+        $this->assertInstanceOf(LayeredError::class, $error);
+        $this->assertSame('loading user: {"code":404}', $error->format());
+    }
+
+    #[Test]
+    public function expectErrType_withMatchingType_returns_error(): void
+    {
+        $exception = new \RuntimeException('boom');
+        $this->assertSame($exception, (new Err($exception))->expectErrType(\Throwable::class));
+        $this->assertSame('error', (new Err('error'))->expectErrType('string'));
+        $this->assertSame(404, (new Err(404))->expectErrType('int|string'));
+    }
+
+    #[Test]
+    public function expectErrType_withMismatchingType_throws_type_error(): void
+    {
+        $err = new Err(new \RuntimeException('boom'));
+        $this->expectException(\TypeError::class);
+        $this->expectExceptionMessage('Result::expectErrType() expected LogicException, RuntimeException given');
+        $err->expectErrType(\LogicException::class);
+    }
+
+    #[Test]
+    public function expectErrType_withMismatchingScalarType_throws_type_error(): void
+    {
+        $err = new Err('error');
+        $this->expectException(\TypeError::class);
+        $err->expectErrType('int');
+    }
+
+    #[Test]
+    public function rescueMap_recovers_into_ok(): void
+    {
+        $result = (new Err('not found'))->rescueMap(fn (string $e): string => "default ({$e})", fn (int $x): int => $x * 10);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame('default (not found)', $result->unwrap());
+    }
+
+    #[Test]
+    public function toResponse_builds_error_envelope(): void
+    {
+        $this->assertSame(['success' => false, 'error' => 'boom'], (new Err(new \RuntimeException('boom')))->toResponse());
+        $this->assertSame(['success' => false, 'error' => ['field' => 'required']], (new Err(['field' => 'required']))->toResponse());
+    }
+
+    #[Test]
+    public function toResponse_withCustomKeysAndStatusMapping_builds_error_envelope(): void
+    {
+        $response = (new Err('not found'))->toResponse([
+            'successKey' => 'ok',
+            'errorKey' => 'message',
+            'errStatus' => fn (string $error): int => $error === 'not found' ? 404 : 500,
+        ]);
+        $this->assertSame(['ok' => false, 'message' => 'not found', 'status' => 404], $response);
+    }
+
+    #[Test]
+    public function toResponse_usesCustomErrorFormatter(): void
+    {
+        Results::setErrorFormatter(fn (mixed $error): string => \is_string($error) ? strtoupper($error) : 'unknown');
+        $this->assertSame(['success' => false, 'error' => 'NOT FOUND', 'status' => 404], (new Err('not found'))->toResponse(['errStatus' => 404]));
+    }
+
+    #[Test]
+    public function andThenAll_does_not_call_function(): void
+    {
+        $err = new Err('error');
+        $called = false;
+        $result = $err->andThenAll(function () use (&$called): array {
+            $called = true;
+
+            return [];
+        });
+        $this->assertFalse($called);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function constrain_does_not_call_schema(): void
+    {
+        $err = new Err('error');
+        $called = false;
+        $result = $err->constrain(function () use (&$called): Result {
+            $called = true;
+
+            return new Ok(1);
+        });
+        $this->assertFalse($called);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function recoverIf_whenPredicateHolds_recovers_into_ok(): void
+    {
+        $result = (new Err(404))->recoverIf(fn (int $status): bool => $status === 404, fn (int $status): array => []);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame([], $result->unwrap());
+    }
+
+    #[Test]
+    public function recoverIf_whenPredicateFails_keeps_err_without_calling_handler(): void
+    {
+        $err = new Err(500);
+        $called = false;
+        $result = $err->recoverIf(fn (int $status): bool => $status === 404, function () use (&$called): array {
+            $called = true;
+
+            return [];
+        });
+        $this->assertFalse($called);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function mapEntries_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->mapEntries(fn ($key, $value) => [$key, $value]));
+    }
+
+    #[Test]
+    public function expectIn_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->expectIn(['error'], 'not allowed'));
+    }
+
+    #[Test]
+    public function snapshot_is_not_affected_by_mutating_the_original(): void
+    {
+        $error = new \ArrayObject(['code' => 500]);
+        $snapshot = (new Err($error))->snapshot();
+
+        $error['code'] = 404;
+        $this->assertSame(500, $snapshot->unwrapErr()['code']);
+    }
+
+    #[Test]
+    public function snapshot_withSelfReferentialArray_marks_the_recursion(): void
+    {
+        $errors = ['x'];
+        $errors['self'] = &$errors;
+
+        $this->assertSame(['x', 'self' => ['x', 'self' => '*RECURSION*']], (new Err($errors))->snapshot()->unwrapErr());
+    }
+
+    #[Test]
+    public function flattenErrors_flattens_nested_error_arrays_and_results(): void
+    {
+        $err = new Err([
+            'name' => ['required', 'too short'],
+            'address' => ['zip' => ['invalid'], 'city' => new Err(['unknown city'])],
+            'age' => new Ok(20),
+            'email invalid',
+        ]);
+        $this->assertSame(['required', 'too short', 'invalid', 'unknown city', 'email invalid'], $err->flattenErrors()->unwrapErr());
+    }
+
+    #[Test]
+    public function flattenErrors_withNonArrayError_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->flattenErrors());
+    }
+
+    #[Test]
+    public function flattenErrors_withSelfReferentialArray_marks_the_recursion(): void
+    {
+        $errors = ['x'];
+        $errors['self'] = &$errors;
+
+        $this->assertSame(['x', 'x', '*RECURSION*'], (new Err($errors))->flattenErrors()->unwrapErr());
+    }
+
+    #[Test]
+    public function tapValueInto_leaves_target_untouched(): void
+    {
+        $err = new Err('error');
+        $target = 'unchanged';
+        $this->assertSame($err, $err->tapValueInto($target));
+        $this->assertSame('unchanged', $target);
+    }
+
+    #[Test]
+    public function mapRecursive_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->mapRecursive(fn ($leaf) => $leaf));
+    }
+
+    #[Test]
+    public function expectInstanceOf_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->expectInstanceOf(\DateTimeInterface::class));
+    }
+
+    #[Test]
+    public function branch_returns_err_tag_and_error(): void
+    {
+        [$tag, $payload] = (new Err('error'))->branch();
+        $this->assertSame('err', $tag);
+        $this->assertSame('error', $payload);
+    }
+
+    #[Test]
+    public function withFallbackChain_tries_sources_in_order_until_ok(): void
+    {
+        $calls = [];
+        $source = function (string $name, Result $result) use (&$calls): \Closure {
+            return function (string $error) use (&$calls, $name, $result): Result {
+                $calls[] = "{$name}({$error})";
+
+                return $result;
+            };
+        };
+        $result = (new Err('primary down'))->withFallbackChain(
+            $source('replica', new Err('replica down')),
+            $source('cache', new Ok('cached')),
+            $source('default', new Ok('default')),
+        );
+        $this->assertSame('cached', $result->unwrap());
+        $this->assertSame(['replica(primary down)', 'cache(primary down)'], $calls);
+    }
+
+    #[Test]
+    public function withFallbackChain_whenAllFail_returns_last_err(): void
+    {
+        $result = (new Err('primary down'))->withFallbackChain(
+            fn (): Result => new Err('replica down'),
+            fn (): Result => new Err('cache down'),
+        );
+        $this->assertSame('cache down', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function expectNonNull_returns_self(): void
+    {
+        $err = new Err(null);
+        $this->assertSame($err, $err->expectNonNull('missing'));
+    }
+
+    #[Test]
+    public function ensureArray_returns_self(): void
+    {
+        $err = new Err('error');
+        $this->assertSame($err, $err->ensureArray());
+    }
+
     /**
      * Widens a literal type to int (covariant templates preserve constant types).
      */
@@ -393,6 +987,18 @@ class ErrTest extends TestCase
     {
         return $value;
     }
+
+    /**
+     * Widens a variant to Result, so a never-returning call on it does not end the block for static analysis.
+     *
+     * @param Result<mixed, mixed> $result
+     *
+     * @return Result<mixed, mixed>
+     */
+    private static function asResult(Result $result): Result
+    {
+        return $result;
+    }
 }
 
 /**
@@ -0,0 +1,14 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests\Fixtures;
+
+/**
+ * Fixture for Results::fromEnum: a pure enum.
+ */
+enum Light
+{
+    case Green;
+    case Red;
+}
@@ -0,0 +1,33 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests\Fixtures;
+
+use Valbeat\Result\Span;
+
+/**
+ * Fixture for Results::span: a span appending its events to its tracer.
+ */
+final class RecordingSpan implements Span
+{
+    public function __construct(
+        private readonly RecordingTracer $tracer,
+    ) {
+    }
+
+    public function setTag(string $key, string|int|float|bool $value): void
+    {
+        $this->tracer->events[] = \sprintf('tag %s=%s', $key, \is_bool($value) ? var_export($value, true) : (string) $value);
+    }
+
+    public function recordError(string $message): void
+    {
+        $this->tracer->events[] = "error {$message}";
+    }
+
+    public function finish(): void
+    {
+        $this->tracer->events[] = 'finish';
+    }
+}
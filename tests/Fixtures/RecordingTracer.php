@@ -0,0 +1,26 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests\Fixtures;
+
+use Valbeat\Result\Span;
+use Valbeat\Result\Tracer;
+
+/**
+ * Fixture for Results::span: a tracer recording span events in order.
+ */
+final class RecordingTracer implements Tracer
+{
+    /**
+     * @var list<string>
+     */
+    public array $events = [];
+
+    public function startSpan(string $name): Span
+    {
+        $this->events[] = "start {$name}";
+
+        return new RecordingSpan($this);
+    }
+}
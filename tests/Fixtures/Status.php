@@ -0,0 +1,15 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests\Fixtures;
+
+/**
+ * Fixture for Results::fromEnum: a backed enum.
+ */
+enum Status: string
+{
+    case Completed = 'completed';
+    case Skipped = 'skipped';
+    case Failed = 'failed';
+}
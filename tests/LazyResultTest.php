@@ -0,0 +1,81 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests;
+
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+use Valbeat\Result\Err;
+use Valbeat\Result\Ok;
+
+class LazyResultTest extends TestCase
+{
+    #[Test]
+    public function mapLazy_does_not_run_until_force(): void
+    {
+        $called = false;
+        $lazy = (new Ok([1, 2]))->mapLazy(function ($x) use (&$called) {
+            $called = true;
+
+            return $x;
+        });
+        $this->assertFalse($called);
+        $lazy->force();
+        $this->assertTrue($called);
+    }
+
+    #[Test]
+    public function force_composes_transforms_in_order(): void
+    {
+        $result = (new Ok(['a' => 1, 'b' => 2]))
+            ->mapLazy(fn ($x) => $x + 1)
+            ->mapLazy(fn ($x) => $x * 10)
+            ->force();
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(['a' => 20, 'b' => 30], $result->unwrap());
+    }
+
+    #[Test]
+    public function force_applies_all_transforms_per_element_in_a_single_pass(): void
+    {
+        $calls = [];
+        (new Ok([1, 2]))
+            ->mapLazy(function ($x) use (&$calls) {
+                $calls[] = "f($x)";
+
+                return $x;
+            })
+            ->mapLazy(function ($x) use (&$calls) {
+                $calls[] = "g($x)";
+
+                return $x;
+            })
+            ->force();
+        $this->assertSame(['f(1)', 'g(1)', 'f(2)', 'g(2)'], $calls);
+    }
+
+    #[Test]
+    public function force_onErr_runs_nothing_and_returns_err(): void
+    {
+        $err = new Err('error');
+        $called = false;
+        $result = $err
+            ->mapLazy(function ($x) use (&$called) {
+                $called = true;
+
+                return $x;
+            })
+            ->force();
+        $this->assertSame($err, $result);
+        $this->assertFalse($called);
+    }
+
+    #[Test]
+    public function force_withNonArrayValue_throws_type_error(): void
+    {
+        $lazy = (new Ok(42))->mapLazy(fn ($x) => $x);
+        $this->expectException(\TypeError::class);
+        $lazy->force();
+    }
+}
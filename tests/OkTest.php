@@ -8,6 +8,7 @@ use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
 use Valbeat\Result\Err;
 use Valbeat\Result\Ok;
+use Valbeat\Result\Result;
 use Valbeat\Result\UnwrapException;
 
 class OkTest extends TestCase
@@ -97,6 +98,21 @@ class OkTest extends TestCase
         $ok->unwrapErr();
     }
 
+    #[Test]
+    public function unwrapErr_reportsCallerFileAndLine(): void
+    {
+        $ok = self::asResult(new Ok(42));
+        $line = __LINE__ + 3;
+
+        try {
+            $ok->unwrapErr();
+            $this->fail('UnwrapException was not thrown');
+        } catch (UnwrapException $e) {
+            $this->assertSame(__FILE__, $e->getFile());
+            $this->assertSame($line, $e->getLine());
+        }
+    }
+
     #[Test]
     public function expectErr_throwsUnwrapException_withValueInMessage(): void
     {
@@ -227,6 +243,36 @@ class OkTest extends TestCase
         $this->assertSame(42, $result->unwrap());
     }
 
+    #[Test]
+    public function then_whenCallbackReturnsValue_wraps_in_ok(): void
+    {
+        $ok = new Ok(10);
+        $result = $ok->then(fn ($x) => $x * 2);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(20, $result->unwrap());
+    }
+
+    #[Test]
+    public function then_whenCallbackReturnsResult_returns_it_as_is(): void
+    {
+        $ok = new Ok(10);
+        $err = new Err('too large');
+        $result = $ok->then(fn ($x) => $err);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function catch_does_not_call_function(): void
+    {
+        $ok = new Ok(42);
+        $called = false;
+        $result = $ok->catch(function () use (&$called) {
+            $called = true;
+        });
+        $this->assertFalse($called);
+        $this->assertSame($ok, $result);
+    }
+
     #[Test]
     public function match_calls_ok_function(): void
     {
@@ -287,6 +333,687 @@ class OkTest extends TestCase
         $this->assertSame(22, $result->unwrap()); // (10 * 2) + 5 - 3 = 22
     }
 
+    #[Test]
+    public function split_returns_value_and_null(): void
+    {
+        $ok = new Ok(42);
+        [$value, $error] = $ok->split();
+        $this->assertSame(42, $value);
+        $this->assertNull($error);
+    }
+
+    #[Test]
+    public function mapValues_applies_function_to_each_value_preserving_keys(): void
+    {
+        $ok = new Ok(['a' => 1, 'b' => 2]);
+        $mapped = $ok->mapValues(fn ($x) => $x * 10);
+        $this->assertInstanceOf(Ok::class, $mapped);
+        $this->assertSame(['a' => 10, 'b' => 20], $mapped->unwrap());
+    }
+
+    #[Test]
+    public function mapKeys_applies_function_to_each_key_preserving_values(): void
+    {
+        $ok = new Ok(['a' => 1, 'b' => 2]);
+        $mapped = $ok->mapKeys(fn ($key) => strtoupper((string) $key));
+        $this->assertInstanceOf(Ok::class, $mapped);
+        $this->assertSame(['A' => 1, 'B' => 2], $mapped->unwrap());
+    }
+
+    #[Test]
+    public function mapEntries_rekeys_and_revalues_simultaneously(): void
+    {
+        $ok = new Ok(['alice' => 30, 'bob' => 25]);
+        $mapped = $ok->mapEntries(fn ($name, $age) => [strtoupper((string) $name), "{$name} is {$age}"]);
+        $this->assertSame(['ALICE' => 'alice is 30', 'BOB' => 'bob is 25'], $mapped->unwrap());
+    }
+
+    #[Test]
+    public function mapEntries_withNonArrayValue_throws_type_error(): void
+    {
+        $ok = new Ok(42);
+        $this->expectException(\TypeError::class);
+        $this->expectExceptionMessage('Result::mapEntries() requires an array success value, int given');
+        $ok->mapEntries(fn ($key, $value) => [$key, $value]);
+    }
+
+    #[Test]
+    public function mapRecursive_maps_scalar_leaves_preserving_structure(): void
+    {
+        $ok = new Ok(['name' => ' alice ', 'tags' => [' a ', ' b '], 'address' => ['city' => ' tokyo ', 'zip' => null]]);
+        $mapped = $ok->mapRecursive(fn ($leaf) => \is_string($leaf) ? trim($leaf) : $leaf);
+        $this->assertSame(['name' => 'alice', 'tags' => ['a', 'b'], 'address' => ['city' => 'tokyo', 'zip' => null]], $mapped->unwrap());
+    }
+
+    #[Test]
+    public function mapRecursive_beyondMaxDepth_throws(): void
+    {
+        $ok = new Ok([[[1]]]);
+        $this->assertSame([[[2]]], $ok->mapRecursive(fn ($leaf) => \is_int($leaf) ? $leaf * 2 : $leaf, maxDepth: 3)->unwrap());
+        $this->expectException(\UnexpectedValueException::class);
+        $ok->mapRecursive(fn ($leaf) => $leaf, maxDepth: 2);
+    }
+
+    #[Test]
+    public function mapRecursive_withNonArrayValue_throws_type_error(): void
+    {
+        $ok = new Ok('not an array');
+        $this->expectException(\TypeError::class);
+        $ok->mapRecursive(fn ($leaf) => $leaf);
+    }
+
+    #[Test]
+    public function mapValues_withNonArrayValue_throws_type_error(): void
+    {
+        $ok = new Ok(42);
+        $this->expectException(\TypeError::class);
+        $this->expectExceptionMessage('Result::mapValues() requires an array success value, int given');
+        $ok->mapValues(fn ($x) => $x);
+    }
+
+    #[Test]
+    public function mapKeys_withNonArrayValue_throws_type_error(): void
+    {
+        $ok = new Ok('not an array');
+        $this->expectException(\TypeError::class);
+        $ok->mapKeys(fn ($key) => $key);
+    }
+
+    #[Test]
+    public function expectType_withMatchingType_returns_value(): void
+    {
+        $this->assertSame(42, (new Ok(42))->expectType('int'));
+        $this->assertSame('42', (new Ok('42'))->expectType('int|string'));
+        $this->assertNull((new Ok(null))->expectType('?int'));
+
+        $exception = new \RuntimeException('boom');
+        $this->assertSame($exception, (new Ok($exception))->expectType(\Throwable::class));
+    }
+
+    #[Test]
+    public function expectType_withMismatchingType_throws_type_error(): void
+    {
+        $ok = new Ok(4.2);
+        $this->expectException(\TypeError::class);
+        $this->expectExceptionMessage('Result::expectType() expected int|string, float given');
+        $ok->expectType('int|string');
+    }
+
+    #[Test]
+    public function expectType_withMismatchingClass_throws_type_error(): void
+    {
+        $ok = new Ok(new \stdClass());
+        $this->expectException(\TypeError::class);
+        $ok->expectType(\Countable::class);
+    }
+
+    #[Test]
+    public function tee_writes_value_to_stream_and_returns_self(): void
+    {
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        $ok = new Ok(42);
+        $result = $ok->tee($stream);
+        rewind($stream);
+        $this->assertSame($ok, $result);
+        $this->assertSame('42' . \PHP_EOL, stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function tee_withFormatter_writes_formatted_value(): void
+    {
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        (new Ok(['id' => 1]))->tee($stream, fn ($value) => (string) json_encode($value));
+        rewind($stream);
+        $this->assertSame('{"id":1}' . \PHP_EOL, stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function toEither_returns_right_with_value(): void
+    {
+        $this->assertSame(['Right' => 42], (new Ok(42))->toEither());
+    }
+
+    #[Test]
+    public function expectShape_withAllKeysPresent_returns_self(): void
+    {
+        $ok = new Ok(['id' => 1, 'user' => ['id' => 7, 'name' => null]]);
+        $this->assertSame($ok, $ok->expectShape(['id', 'user.id', 'user.name']));
+    }
+
+    #[Test]
+    public function expectShape_withMissingKeys_returns_err_listing_them(): void
+    {
+        $ok = new Ok(['id' => 1, 'user' => ['name' => 'alice']]);
+        $result = $ok->expectShape(['id', 'email', 'user.id', 'user.name']);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame(['missing' => ['email', 'user.id']], $result->unwrapErr());
+    }
+
+    #[Test]
+    public function expectShape_withNestedPathThroughScalar_reports_missing(): void
+    {
+        $ok = new Ok(['user' => 'alice', 'items' => [['sku' => 'A1']]]);
+        $result = $ok->expectShape(['user.id', 'items.0.sku']);
+        $this->assertSame(['missing' => ['user.id']], $result->unwrapErr());
+    }
+
+    #[Test]
+    public function pluck_withArrayKey_returns_ok_with_field(): void
+    {
+        $result = (new Ok(['id' => 7, 'name' => 'alice']))->pluck('name');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame('alice', $result->unwrap());
+    }
+
+    #[Test]
+    public function pluck_withObjectProperty_returns_ok_with_field(): void
+    {
+        $user = new \stdClass();
+        $user->email = null;
+        $result = (new Ok($user))->pluck('email');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertNull($result->unwrap());
+    }
+
+    #[Test]
+    public function pluck_withMissingKey_returns_err(): void
+    {
+        $result = (new Ok(['id' => 7]))->pluck('name');
+        $this->assertInstanceOf(Err::class, $result);
+        $error = $result->unwrapErr();
+        $this->assertInstanceOf(\OutOfBoundsException::class, $error);
+        $this->assertSame('key "name" does not exist in the array success value', $error->getMessage());
+    }
+
+    #[Test]
+    public function pluck_withMissingProperty_returns_err(): void
+    {
+        $result = (new Ok(new \stdClass()))->pluck('name');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertInstanceOf(\OutOfBoundsException::class, $result->unwrapErr());
+    }
+
+    #[Test]
+    public function pluck_withNonPublicProperty_returns_err(): void
+    {
+        $result = (new Ok(new Ok(5)))->pluck('value');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertInstanceOf(\OutOfBoundsException::class, $result->unwrapErr());
+    }
+
+    #[Test]
+    public function when_conditionTrue_applies_function(): void
+    {
+        $result = (new Ok(10))->when(true, fn ($x) => new Ok($x * 2));
+        $this->assertSame(20, $result->unwrap());
+    }
+
+    #[Test]
+    public function when_conditionFalse_returns_self(): void
+    {
+        $ok = new Ok(10);
+        $this->assertSame($ok, $ok->when(false, fn ($x) => new Err('not called')));
+    }
+
+    #[Test]
+    public function whenWith_evaluates_condition_against_value(): void
+    {
+        $double = fn ($x) => new Ok($x * 2);
+        $this->assertSame(20, (new Ok(self::asInt(10)))->whenWith(fn ($x) => $x > 5, $double)->unwrap());
+        $this->assertSame(3, (new Ok(self::asInt(3)))->whenWith(fn ($x) => $x > 5, $double)->unwrap());
+    }
+
+    #[Test]
+    public function ensureNotEmpty_withEmptyValues_returns_err(): void
+    {
+        foreach ([null, '', [], false] as $empty) {
+            $result = (new Ok($empty))->ensureNotEmpty('empty');
+            $this->assertTrue($result->isErr(), var_export($empty, true) . ' should be empty');
+            $this->assertSame('empty', $result->unwrapErr());
+        }
+    }
+
+    #[Test]
+    public function ensureNotEmpty_withNonEmptyValue_returns_self(): void
+    {
+        $ok = new Ok(['item']);
+        $this->assertSame($ok, $ok->ensureNotEmpty('empty'));
+    }
+
+    #[Test]
+    public function ensureNotEmpty_treatsZerosAsNonEmptyByDefault(): void
+    {
+        foreach ([0, 0.0, '0'] as $zero) {
+            $this->assertTrue((new Ok($zero))->ensureNotEmpty('empty')->isOk());
+            $this->assertTrue((new Ok($zero))->ensureNotEmpty('empty', zeroIsEmpty: true)->isErr());
+        }
+    }
+
+    #[Test]
+    public function toStringOr_withScalar_returns_string(): void
+    {
+        $this->assertSame('42', (new Ok(42))->toStringOr('n/a'));
+        $this->assertSame('alice', (new Ok('alice'))->toStringOr('n/a'));
+    }
+
+    #[Test]
+    public function toStringOr_withStringable_returns_string(): void
+    {
+        $value = new class () implements \Stringable {
+            public function __toString(): string
+            {
+                return 'stringable value';
+            }
+        };
+        $this->assertSame('stringable value', (new Ok($value))->toStringOr('n/a'));
+    }
+
+    #[Test]
+    public function toStringOr_withNonStringable_returns_fallback(): void
+    {
+        $this->assertSame('n/a', (new Ok(new \stdClass()))->toStringOr('n/a'));
+        $this->assertSame('n/a', (new Ok(['a']))->toStringOr('n/a'));
+    }
+
+    #[Test]
+    public function intoNullable_returns_value(): void
+    {
+        $this->assertSame(42, (new Ok(42))->intoNullable());
+        $this->assertNull((new Ok(null))->intoNullable());
+    }
+
+    #[Test]
+    public function intoNullableStrict_returns_non_null_value(): void
+    {
+        $this->assertSame(42, (new Ok(42))->intoNullableStrict());
+    }
+
+    #[Test]
+    public function intoNullableStrict_withNullValue_throws(): void
+    {
+        $ok = new Ok(null);
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage('called Result::intoNullableStrict() on an Ok(null) value');
+        $ok->intoNullableStrict();
+    }
+
+    #[Test]
+    public function tapOn_calls_ok_handler_and_returns_self(): void
+    {
+        $ok = new Ok(42);
+        $calls = [];
+        $result = $ok->tapOn([
+            'ok' => function ($value) use (&$calls) {
+                $calls[] = "ok: $value";
+            },
+            'err' => function ($error) use (&$calls) {
+                $calls[] = 'err';
+            },
+        ]);
+        $this->assertSame($ok, $result);
+        $this->assertSame(['ok: 42'], $calls);
+    }
+
+    #[Test]
+    public function tapOn_withOnlyErrHandler_does_nothing(): void
+    {
+        $ok = new Ok(42);
+        $called = false;
+        $result = $ok->tapOn(['err' => function () use (&$called) {
+            $called = true;
+        }]);
+        $this->assertSame($ok, $result);
+        $this->assertFalse($called);
+    }
+
+    #[Test]
+    public function expectOne_withSingleElement_returns_ok_with_element(): void
+    {
+        $result = (new Ok(['user-7' => 'alice']))->expectOne('not exactly one');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame('alice', $result->unwrap());
+    }
+
+    #[Test]
+    public function expectOne_withEmptyOrMultipleElements_returns_err(): void
+    {
+        $this->assertSame('not exactly one', (new Ok([]))->expectOne('not exactly one')->unwrapErr());
+        $this->assertSame('not exactly one', (new Ok(['a', 'b']))->expectOne('not exactly one')->unwrapErr());
+    }
+
+    #[Test]
+    public function expectOne_withNonArrayValue_throws_type_error(): void
+    {
+        $ok = new Ok('alice');
+        $this->expectException(\TypeError::class);
+        $ok->expectOne('not exactly one');
+    }
+
+    #[Test]
+    public function orThrowHttp_returns_value(): void
+    {
+        $this->assertSame(42, (new Ok(42))->orThrowHttp(404));
+    }
+
+    #[Test]
+    public function cast_whenValueIsCoercible_returns_ok_with_coerced_value(): void
+    {
+        $this->assertSame(12, (new Ok('12'))->cast('int')->unwrap());
+        $this->assertSame(-3, (new Ok('-3'))->cast('int')->unwrap());
+        $this->assertSame(2, (new Ok(2.0))->cast('int')->unwrap());
+        $this->assertSame(1.5, (new Ok('1.5'))->cast('float')->unwrap());
+        $this->assertSame(3.0, (new Ok(3))->cast('float')->unwrap());
+        $this->assertSame('42', (new Ok(42))->cast('string')->unwrap());
+        $this->assertTrue((new Ok('TRUE'))->cast('bool')->unwrap());
+        $this->assertFalse((new Ok(0))->cast('bool')->unwrap());
+    }
+
+    #[Test]
+    public function cast_whenValueIsNotCoercible_returns_err(): void
+    {
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok('abc'))->cast('int')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok('12abc'))->cast('int')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok(1.5))->cast('int')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok('99999999999999999999'))->cast('int')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok('1.5x'))->cast('float')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok([]))->cast('string')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok('yes'))->cast('bool')->unwrapErr());
+        $this->assertInstanceOf(\UnexpectedValueException::class, (new Ok(2))->cast('bool')->unwrapErr());
+    }
+
+    #[Test]
+    public function cast_withUnsupportedType_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        (new Ok(1))->cast('array'); // @phpstan-ignore argument.type
+    }
+
+    #[Test]
+    public function isNested_whenValueIsResult_returns_true(): void
+    {
+        $this->assertTrue((new Ok(new Ok(1)))->isNested());
+        $this->assertTrue((new Ok(new Err('error')))->isNested());
+    }
+
+    #[Test]
+    public function isNested_whenValueIsNotResult_returns_false(): void
+    {
+        $this->assertFalse((new Ok(1))->isNested());
+        $this->assertFalse((new Ok([new Ok(1)]))->isNested());
+    }
+
+    #[Test]
+    public function toString_renders_the_value(): void
+    {
+        $this->assertSame('Ok(42)', (string) new Ok(42));
+        $this->assertSame('Ok(array)', (string) new Ok([1, 2]));
+    }
+
+    #[Test]
+    public function withMetadata_is_readable_and_does_not_change_the_value(): void
+    {
+        $ok = (new Ok(1))->withMetadata('traceId', 'abc');
+        $this->assertSame('abc', $ok->metadata('traceId'));
+        $this->assertNull($ok->metadata('missing'));
+        $this->assertSame(1, $ok->unwrap());
+    }
+
+    #[Test]
+    public function withMetadata_is_propagated_through_a_two_step_chain(): void
+    {
+        $result = (new Ok(2))
+            ->withMetadata('traceId', 'abc')
+            ->map(fn (int $x): int => $x * 10)
+            ->andThen(fn (int $x): Result => $x > 10 ? new Err('too large') : new Ok($x));
+        $this->assertSame('too large', $result->unwrapErr());
+        $this->assertSame('abc', $result->metadata('traceId'));
+    }
+
+    #[Test]
+    public function withMetadata_keeps_entries_of_the_result_returned_by_andThen(): void
+    {
+        $result = (new Ok(1))
+            ->withMetadata('traceId', 'outer')
+            ->withMetadata('step', 'first')
+            ->andThen(fn (int $x): Result => (new Ok($x))->withMetadata('step', 'second'));
+        $this->assertSame('outer', $result->metadata('traceId'));
+        $this->assertSame('second', $result->metadata('step'));
+    }
+
+    #[Test]
+    public function withMetadata_does_not_affect_equality(): void
+    {
+        $this->assertEquals(new Ok(1), (new Ok(1))->withMetadata('traceId', 'abc'));
+        $this->assertTrue((new Ok(1))->withMetadata('traceId', 'abc') == new Ok(1));
+    }
+
+    #[Test]
+    public function withMetadata_is_propagated_to_an_err_derived_from_ok(): void
+    {
+        $result = (new Ok(['id' => 7]))
+            ->withMetadata('traceId', 'abc')
+            ->pluck('name');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('abc', $result->metadata('traceId'));
+    }
+
+    #[Test]
+    public function mapErrChain_returns_self(): void
+    {
+        $ok = new Ok(1);
+        $this->assertSame($ok, $ok->mapErrChain('loading config'));
+    }
+
+    #[Test]
+    public function expectErrType_throwsUnwrapException_withValueInMessage(): void
+    {
+        $ok = new Ok(42);
+        $this->expectException(UnwrapException::class);
+        $this->expectExceptionMessage('called Result::expectErrType() on an Ok value: 42');
+        $ok->expectErrType('string');
+    }
+
+    #[Test]
+    public function rescueMap_maps_value(): void
+    {
+        $result = (new Ok(2))->rescueMap(fn (string $e): int => 0, fn (int $x): int => $x * 10);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(20, $result->unwrap());
+    }
+
+    #[Test]
+    public function toResponse_builds_success_envelope(): void
+    {
+        $this->assertSame(['success' => true, 'data' => ['id' => 1]], (new Ok(['id' => 1]))->toResponse());
+    }
+
+    #[Test]
+    public function toResponse_withCustomKeysAndStatus_builds_success_envelope(): void
+    {
+        $response = (new Ok(['id' => 1]))->toResponse(['successKey' => 'ok', 'dataKey' => 'result', 'okStatus' => 200, 'statusKey' => 'code']);
+        $this->assertSame(['ok' => true, 'result' => ['id' => 1], 'code' => 200], $response);
+    }
+
+    #[Test]
+    public function andThenAll_whenAllOk_returns_ok_with_values(): void
+    {
+        $result = (new Ok([1, 2, 3]))->andThenAll(fn (array $ids): array => array_map(fn (int $id): Result => new Ok("user{$id}"), $ids));
+        $this->assertSame(['user1', 'user2', 'user3'], $result->unwrap());
+    }
+
+    #[Test]
+    public function andThenAll_whenAnyErr_returns_first_err(): void
+    {
+        $result = (new Ok([1, 2, 3]))->andThenAll(fn (array $ids): array => array_map(fn (int $id): Result => $id === 1 ? new Ok($id) : new Err("missing {$id}"), $ids));
+        $this->assertSame('missing 2', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function constrain_whenSchemaCoerces_returns_ok_with_coerced_value(): void
+    {
+        $schema = fn (array $payload): Result => isset($payload['age']) && is_numeric($payload['age'])
+            ? new Ok(['age' => (int) $payload['age']])
+            : new Err(['age' => 'must be numeric']);
+        $this->assertSame(['age' => 42], (new Ok(['age' => '42']))->constrain($schema)->unwrap());
+    }
+
+    #[Test]
+    public function constrain_whenSchemaRejects_returns_err_with_details(): void
+    {
+        $schema = fn (array $payload): Result => isset($payload['age']) && is_numeric($payload['age'])
+            ? new Ok(['age' => (int) $payload['age']])
+            : new Err(['age' => 'must be numeric']);
+        $this->assertSame(['age' => 'must be numeric'], (new Ok(['age' => 'old']))->constrain($schema)->unwrapErr());
+    }
+
+    #[Test]
+    public function recoverIf_returns_self(): void
+    {
+        $ok = new Ok(1);
+        $this->assertSame($ok, $ok->recoverIf(fn (): bool => true, fn (): int => 0));
+    }
+
+    #[Test]
+    public function expectIn_whenValueIsAllowed_returns_self(): void
+    {
+        $ok = new Ok('draft');
+        $this->assertSame($ok, $ok->expectIn(['draft', 'published'], 'unknown status'));
+    }
+
+    #[Test]
+    public function expectIn_whenValueIsNotAllowed_returns_err(): void
+    {
+        $this->assertSame('unknown status', (new Ok('deleted'))->expectIn(['draft', 'published'], 'unknown status')->unwrapErr());
+    }
+
+    #[Test]
+    public function expectIn_comparesStrictlyByDefault(): void
+    {
+        $this->assertTrue((new Ok('1'))->expectIn([1, 2], 'not allowed')->isErr());
+        $this->assertTrue((new Ok('1'))->expectIn([1, 2], 'not allowed', strict: false)->isOk());
+    }
+
+    #[Test]
+    public function snapshot_is_not_affected_by_mutating_the_original(): void
+    {
+        $user = new \stdClass();
+        $user->name = 'alice';
+        $ok = new Ok(['users' => [$user], 'count' => 1]);
+        $snapshot = $ok->snapshot();
+
+        $user->name = 'bob';
+        $this->assertSame('alice', $snapshot->unwrap()['users'][0]->name);
+        $this->assertSame(1, $snapshot->unwrap()['count']);
+
+        $customer = new \stdClass();
+        $customer->name = 'alice';
+        $order = new \stdClass();
+        $order->customer = $customer;
+        $order->billedTo = $customer;
+        $snapshot = (new Ok($order))->snapshot();
+
+        $order->customer->name = 'bob';
+        $copy = $snapshot->unwrap();
+        $this->assertSame('alice', $copy->customer->name);
+        $this->assertNotSame($customer, $copy->customer);
+        $this->assertSame($copy->customer, $copy->billedTo);
+    }
+
+    #[Test]
+    public function snapshot_copies_readonly_objects_deeply(): void
+    {
+        $user = new \stdClass();
+        $user->name = 'alice';
+        $snapshot = (new Ok(new Ok($user)))->snapshot();
+
+        $user->name = 'bob';
+        $this->assertSame('alice', $snapshot->unwrap()->unwrap()->name);
+    }
+
+    #[Test]
+    public function flattenErrors_returns_self(): void
+    {
+        $ok = new Ok([[1]]);
+        $this->assertSame($ok, $ok->flattenErrors());
+    }
+
+    #[Test]
+    public function tapValueInto_assigns_value_and_returns_self(): void
+    {
+        $ok = new Ok(42);
+        $target = null;
+        $this->assertSame($ok, $ok->tapValueInto($target));
+        $this->assertSame(42, $target);
+    }
+
+    #[Test]
+    public function expectInstanceOf_whenValueIsInstance_keeps_value(): void
+    {
+        $date = new \DateTimeImmutable();
+        $this->assertSame($date, (new Ok($date))->expectInstanceOf(\DateTimeInterface::class)->unwrap());
+    }
+
+    #[Test]
+    public function expectInstanceOf_whenValueIsNotInstance_returns_err(): void
+    {
+        $error = (new Ok(new \stdClass()))->expectInstanceOf(\DateTimeInterface::class)->unwrapErr();
+        $this->assertInstanceOf(\UnexpectedValueException::class, $error);
+        $this->assertSame('expected an instance of DateTimeInterface, stdClass given', $error->getMessage());
+    }
+
+    #[Test]
+    public function branch_returns_ok_tag_and_value(): void
+    {
+        [$tag, $payload] = (new Ok(42))->branch();
+        $this->assertSame('ok', $tag);
+        $this->assertSame(42, $payload);
+    }
+
+    #[Test]
+    public function withFallbackChain_does_not_call_sources(): void
+    {
+        $ok = new Ok(1);
+        $called = false;
+        $result = $ok->withFallbackChain(function () use (&$called): Result {
+            $called = true;
+
+            return new Ok(2);
+        });
+        $this->assertFalse($called);
+        $this->assertSame($ok, $result);
+    }
+
+    #[Test]
+    public function expectNonNull_whenValueIsNull_returns_err(): void
+    {
+        $this->assertSame('missing', (new Ok(null))->expectNonNull('missing')->unwrapErr());
+    }
+
+    #[Test]
+    public function expectNonNull_keeps_falsy_values(): void
+    {
+        foreach ([0, false, '', [], 0.0] as $value) {
+            $ok = new Ok($value);
+            $this->assertSame($ok, $ok->expectNonNull('missing'));
+        }
+    }
+
+    #[Test]
+    public function ensureArray_wraps_single_value_and_keeps_arrays(): void
+    {
+        $this->assertSame([42], (new Ok(42))->ensureArray()->unwrap());
+        $this->assertSame(['a' => 1, 'b' => 2], (new Ok(['a' => 1, 'b' => 2]))->ensureArray()->unwrap());
+    }
+
+    #[Test]
+    public function ensureArray_withNull_wraps_or_empties_depending_on_flag(): void
+    {
+        $this->assertSame([null], (new Ok(null))->ensureArray()->unwrap());
+        $this->assertSame([], (new Ok(null))->ensureArray(nullAsEmpty: true)->unwrap());
+    }
+
     /**
      * Widens a literal type to int (covariant templates preserve constant types).
      */
@@ -294,4 +1021,16 @@ class OkTest extends TestCase
     {
         return $value;
     }
+
+    /**
+     * Widens a variant to Result, so a never-returning call on it does not end the block for static analysis.
+     *
+     * @param Result<mixed, mixed> $result
+     *
+     * @return Result<mixed, mixed>
+     */
+    private static function asResult(Result $result): Result
+    {
+        return $result;
+    }
 }
@@ -0,0 +1,50 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests;
+
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+use Valbeat\Result\Err;
+use Valbeat\Result\Ok;
+
+class PatternTest extends TestCase
+{
+    #[Test]
+    public function run_onOk_returns_ok_arm_value(): void
+    {
+        $value = (new Ok(2))->pattern()
+            ->ok(fn (int $x): string => "value {$x}")
+            ->err(fn (string $e): string => "error {$e}")
+            ->run();
+        $this->assertSame('value 2', $value);
+    }
+
+    #[Test]
+    public function run_onErr_returns_err_arm_value(): void
+    {
+        $value = (new Err('boom'))->pattern()
+            ->ok(fn (int $x): string => "value {$x}")
+            ->err(fn (string $e): string => "error {$e}")
+            ->run();
+        $this->assertSame('error boom', $value);
+    }
+
+    #[Test]
+    public function run_withMissingArm_throws_logic_exception(): void
+    {
+        $pattern = (new Ok(2))->pattern()->ok(fn (int $x): int => $x);
+        $this->expectException(\LogicException::class);
+        $this->expectExceptionMessage('Pattern::run() requires both arms, missing: err');
+        $pattern->run();
+    }
+
+    #[Test]
+    public function run_withoutArms_throws_logic_exception(): void
+    {
+        $this->expectException(\LogicException::class);
+        $this->expectExceptionMessage('missing: ok, err');
+        (new Err('boom'))->pattern()->run();
+    }
+}
@@ -0,0 +1,70 @@
+<?php
+
+declare(strict_types=1);
+
+namespace Valbeat\Result\Tests;
+
+use PHPUnit\Framework\Attributes\Test;
+use PHPUnit\Framework\TestCase;
+use Valbeat\Result\Err;
+use Valbeat\Result\Ok;
+use Valbeat\Result\ResultBuilder;
+use Valbeat\Result\Results;
+
+class ResultBuilderTest extends TestCase
+{
+    #[Test]
+    public function ok_returns_ok_with_value(): void
+    {
+        $result = self::builder()->ok(42);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(42, $result->unwrap());
+    }
+
+    #[Test]
+    public function fail_applies_preset_error_factory(): void
+    {
+        $result = self::builder()->fail('user not found');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertInstanceOf(\DomainException::class, $result->unwrapErr());
+        $this->assertSame('user not found', $result->unwrapErr()->getMessage());
+    }
+
+    #[Test]
+    public function fromBool_true_returns_ok(): void
+    {
+        $result = self::builder()->fromBool(true, 'unused');
+        $this->assertTrue($result->unwrap());
+    }
+
+    #[Test]
+    public function fromBool_false_applies_preset_error_factory(): void
+    {
+        $result = self::builder()->fromBool(false, 'flag was off');
+        $this->assertTrue($result->isErr());
+        $this->assertSame('flag was off', $result->unwrapErr()->getMessage());
+    }
+
+    #[Test]
+    public function fromNullable_withValue_returns_ok(): void
+    {
+        $result = self::builder()->fromNullable('alice', 'unused');
+        $this->assertSame('alice', $result->unwrap());
+    }
+
+    #[Test]
+    public function fromNullable_withNull_applies_preset_error_factory(): void
+    {
+        $result = self::builder()->fromNullable(null, 'no name');
+        $this->assertTrue($result->isErr());
+        $this->assertSame('no name', $result->unwrapErr()->getMessage());
+    }
+
+    /**
+     * @return ResultBuilder<\DomainException>
+     */
+    private static function builder(): ResultBuilder
+    {
+        return Results::withErrorType(static fn (mixed $reason): \DomainException => new \DomainException(\is_string($reason) ? $reason : 'unknown error'));
+    }
+}
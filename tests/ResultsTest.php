@@ -4,12 +4,16 @@ declare(strict_types=1);
 
 namespace Valbeat\Result\Tests;
 
+use PHPUnit\Framework\Attributes\RequiresPhpExtension;
 use PHPUnit\Framework\Attributes\Test;
 use PHPUnit\Framework\TestCase;
 use Valbeat\Result\Err;
 use Valbeat\Result\Ok;
 use Valbeat\Result\Result;
 use Valbeat\Result\Results;
+use Valbeat\Result\Tests\Fixtures\Light;
+use Valbeat\Result\Tests\Fixtures\RecordingTracer;
+use Valbeat\Result\Tests\Fixtures\Status;
 
 class ResultsTest extends TestCase
 {
@@ -40,6 +44,57 @@ class ResultsTest extends TestCase
         $this->assertInstanceOf(\DivisionByZeroError::class, $result->unwrapErr());
     }
 
+    #[Test]
+    public function adapt_whenCallableReturnsFalse_returns_err_with_false_error(): void
+    {
+        $result = Results::adapt(fn () => strpos('abc', 'z'), 'not found');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('not found', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function adapt_whenCallableReturnsValue_returns_ok(): void
+    {
+        $result = Results::adapt(fn () => strpos('abc', 'a'), 'not found');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(0, $result->unwrap());
+    }
+
+    #[Test]
+    public function adapt_whenCallableThrows_returns_err_with_throwable(): void
+    {
+        $exception = new \RuntimeException('boom');
+        $result = Results::adapt(function () use ($exception): bool {
+            throw $exception;
+        }, 'not found');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame($exception, $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromValidator_whenValidatorReturnsTrue_returns_ok_with_value(): void
+    {
+        $result = Results::fromValidator('alice@example.com', fn (string $email) => str_contains($email, '@') ? true : 'invalid email');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame('alice@example.com', $result->unwrap());
+    }
+
+    #[Test]
+    public function fromValidator_whenValidatorReturnsError_returns_err_with_output(): void
+    {
+        $result = Results::fromValidator('', fn (string $name) => $name !== '' ? true : ['name' => 'required']);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame(['name' => 'required'], $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromValidator_onlyStrictTrueCountsAsPass(): void
+    {
+        $result = Results::fromValidator(42, fn (int $value) => 1);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame(1, $result->unwrapErr());
+    }
+
     #[Test]
     public function combine_allOk_returns_ok_with_values_in_order(): void
     {
@@ -78,6 +133,60 @@ class ResultsTest extends TestCase
         $this->assertSame(['a', 'b'], $result->unwrap());
     }
 
+    #[Test]
+    public function combine_withFlatten_flattens_nested_oks_one_level(): void
+    {
+        $result = Results::combine([new Ok(new Ok(1)), new Ok(2), new Ok(new Ok(new Ok(3)))], flatten: true);
+        $this->assertSame(1, $result->unwrap()[0]);
+        $this->assertSame(2, $result->unwrap()[1]);
+        $this->assertEquals(new Ok(3), $result->unwrap()[2]);
+    }
+
+    #[Test]
+    public function combine_withFlatten_returns_inner_err(): void
+    {
+        $result = Results::combine([new Ok(new Ok(1)), new Ok(new Err('inner')), new Err('outer')], flatten: true);
+        $this->assertSame('inner', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function combine_withoutFlatten_keeps_nested_results(): void
+    {
+        $inner = new Ok(1);
+        $this->assertSame([$inner], Results::combine([new Ok($inner)])->unwrap());
+    }
+
+    #[Test]
+    public function collectInto_allOk_appends_values_to_collection(): void
+    {
+        $result = Results::collectInto(
+            [new Ok(1), new Ok(2), new Ok(3)],
+            fn () => new \SplStack(),
+            fn ($stack, $value) => $stack->push($value),
+        );
+        $this->assertInstanceOf(Ok::class, $result);
+        $stack = $result->unwrap();
+        $this->assertInstanceOf(\SplStack::class, $stack);
+        $this->assertSame([3, 2, 1], iterator_to_array($stack, false));
+    }
+
+    #[Test]
+    public function collectInto_withErr_returns_first_err_and_stops_appending(): void
+    {
+        $err = new Err('second failed');
+        $appended = [];
+        $result = Results::collectInto(
+            [new Ok(1), $err, new Ok(3)],
+            fn () => new \SplStack(),
+            function ($stack, $value) use (&$appended) {
+                $appended[] = $value;
+                $stack->push($value);
+            },
+        );
+        $this->assertSame($err, $result);
+        $this->assertSame([1], $appended);
+    }
+
     #[Test]
     public function flatten_okOfOk_returns_inner_ok(): void
     {
@@ -102,6 +211,1285 @@ class ResultsTest extends TestCase
         $this->assertSame($outer, $result);
     }
 
+    #[Test]
+    public function fromTuple_withError_returns_err(): void
+    {
+        $result = Results::fromTuple([null, 'error']);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('error', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromTuple_withValueAndError_errorWins(): void
+    {
+        $result = Results::fromTuple([42, 'error']);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('error', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromTuple_withValue_returns_ok(): void
+    {
+        $result = Results::fromTuple([42, null]);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(42, $result->unwrap());
+    }
+
+    #[Test]
+    public function fromTuple_roundTripsSplit(): void
+    {
+        $this->assertSame(42, Results::fromTuple((new Ok(42))->split())->unwrap());
+        $this->assertSame('error', Results::fromTuple((new Err('error'))->split())->unwrapErr());
+    }
+
+    #[Test]
+    public function fromTuple_bothNull_returns_ok_null_by_default(): void
+    {
+        $result = Results::fromTuple([null, null]);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertNull($result->unwrap());
+    }
+
+    #[Test]
+    public function fromTuple_bothNull_inStrictMode_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::fromTuple([null, null], strict: true);
+    }
+
+    #[Test]
+    public function flattenGenerator_okOfGenerator_yields_each_inner_result_lazily(): void
+    {
+        $produced = 0;
+        $inner = (static function () use (&$produced): \Generator {
+            foreach ([new Ok(1), new Err('bad'), new Ok(3)] as $result) {
+                $produced++;
+                yield $result;
+            }
+        })();
+        $flattened = Results::flattenGenerator(new Ok($inner));
+
+        $this->assertSame(0, $produced);
+        $first = $flattened->current();
+        $this->assertSame(1, $produced);
+        $this->assertInstanceOf(Ok::class, $first);
+        $this->assertSame(1, $first->unwrap());
+
+        $rest = iterator_to_array($flattened, false);
+        $this->assertSame(3, $produced);
+        $this->assertCount(3, $rest);
+        $this->assertSame('bad', $rest[1]->unwrapErr());
+        $this->assertSame(3, $rest[2]->unwrap());
+    }
+
+    #[Test]
+    public function flattenGenerator_err_yields_outer_err_once(): void
+    {
+        $outer = new Err('outer error');
+        $this->assertSame([$outer], iterator_to_array(Results::flattenGenerator($outer), false));
+    }
+
+    #[Test]
+    public function flattenGenerator_whenInnerGeneratorThrows_yields_final_err(): void
+    {
+        $exception = new \RuntimeException('stream broke');
+        $inner = (static function () use ($exception): \Generator {
+            yield new Ok(1);
+
+            throw $exception;
+        })();
+        $results = iterator_to_array(Results::flattenGenerator(new Ok($inner)), false);
+
+        $this->assertCount(2, $results);
+        $this->assertSame(1, $results[0]->unwrap());
+        $this->assertInstanceOf(Err::class, $results[1]);
+        $this->assertSame($exception, $results[1]->unwrapErr());
+    }
+
+    #[Test]
+    public function scan_allOk_yields_running_total(): void
+    {
+        $scanned = Results::scan(
+            [new Ok(1), new Ok(2), new Ok(3)],
+            0,
+            fn (int $sum, int $value) => [$sum + $value, $sum + $value],
+        );
+        $totals = array_map(fn ($result) => $result->unwrap(), iterator_to_array($scanned, false));
+        $this->assertSame([1, 3, 6], $totals);
+    }
+
+    #[Test]
+    public function scan_withErr_yields_err_last_and_stops_consuming(): void
+    {
+        $consumed = 0;
+        $source = (static function () use (&$consumed): \Generator {
+            foreach ([new Ok(1), new Err('bad row'), new Ok(3)] as $result) {
+                $consumed++;
+                yield $result;
+            }
+        })();
+        $scanned = iterator_to_array(Results::scan($source, 0, fn ($sum, $value) => [$sum + $value, $sum + $value]), false);
+
+        $this->assertCount(2, $scanned);
+        $this->assertSame(1, $scanned[0]->unwrap());
+        $this->assertInstanceOf(Err::class, $scanned[1]);
+        $this->assertSame('bad row', $scanned[1]->unwrapErr());
+        $this->assertSame(2, $consumed);
+    }
+
+    #[Test]
+    public function fromSettledArray_summarizes_mixed_results_preserving_keys(): void
+    {
+        $summary = Results::fromSettledArray([
+            'users' => new Ok(3),
+            'orders' => new Err('timeout'),
+            'items' => new Ok(10),
+        ]);
+        $this->assertSame([
+            'ok' => 2,
+            'err' => 1,
+            'values' => ['users' => 3, 'items' => 10],
+            'errors' => ['orders' => 'timeout'],
+        ], $summary);
+    }
+
+    #[Test]
+    public function fromSettledArray_withEmptyArray_returns_empty_summary(): void
+    {
+        $this->assertSame(['ok' => 0, 'err' => 0, 'values' => [], 'errors' => []], Results::fromSettledArray([]));
+    }
+
+    #[Test]
+    public function retryWith_allAttemptsFail_sleeps_with_exponential_backoff_and_returns_last_err(): void
+    {
+        $delays = [];
+        $result = Results::retryWith(
+            ['maxAttempts' => 3, 'baseDelayMs' => 100, 'multiplier' => 2.0],
+            fn (int $attempt) => new Err("attempt $attempt failed"),
+            function (int $milliseconds) use (&$delays) {
+                $delays[] = $milliseconds;
+            },
+        );
+        $this->assertSame([100, 200], $delays);
+        $this->assertSame('attempt 3 failed', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function retryWith_whenAttemptSucceeds_stops_retrying(): void
+    {
+        $delays = [];
+        $result = Results::retryWith(
+            ['maxAttempts' => 5, 'baseDelayMs' => 10],
+            fn (int $attempt) => $attempt === 2 ? new Ok('done') : new Err('not yet'),
+            function (int $milliseconds) use (&$delays) {
+                $delays[] = $milliseconds;
+            },
+        );
+        $this->assertSame([10], $delays);
+        $this->assertSame('done', $result->unwrap());
+    }
+
+    #[Test]
+    public function retryWith_capsDelayAtMaxDelay(): void
+    {
+        $delays = [];
+        Results::retryWith(
+            ['maxAttempts' => 3, 'baseDelayMs' => 100, 'maxDelayMs' => 500, 'multiplier' => 10.0],
+            fn (int $attempt) => new Err('failed'),
+            function (int $milliseconds) use (&$delays) {
+                $delays[] = $milliseconds;
+            },
+        );
+        $this->assertSame([100, 500], $delays);
+    }
+
+    #[Test]
+    public function retryWith_withJitter_keepsDelaysWithinBackoff(): void
+    {
+        $delays = [];
+        Results::retryWith(
+            ['maxAttempts' => 3, 'baseDelayMs' => 100, 'jitter' => true],
+            fn (int $attempt) => new Err('failed'),
+            function (int $milliseconds) use (&$delays) {
+                $delays[] = $milliseconds;
+            },
+        );
+        $this->assertCount(2, $delays);
+        $this->assertGreaterThanOrEqual(0, $delays[0]);
+        $this->assertLessThanOrEqual(100, $delays[0]);
+        $this->assertGreaterThanOrEqual(0, $delays[1]);
+        $this->assertLessThanOrEqual(200, $delays[1]);
+    }
+
+    #[Test]
+    public function retryWith_withZeroMaxAttempts_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::retryWith(['maxAttempts' => 0], fn (int $attempt) => new Ok($attempt));
+    }
+
+    #[Test]
+    public function fromEnum_withOkCase_returns_ok(): void
+    {
+        $result = Results::fromEnum(Status::Completed, [Status::Completed, Status::Skipped]);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(Status::Completed, $result->unwrap());
+    }
+
+    #[Test]
+    public function fromEnum_withOtherCase_returns_err(): void
+    {
+        $result = Results::fromEnum(Status::Failed, [Status::Completed, Status::Skipped]);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame(Status::Failed, $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromEnum_supportsPureEnums(): void
+    {
+        $this->assertTrue(Results::fromEnum(Light::Green, [Light::Green])->isOk());
+        $this->assertTrue(Results::fromEnum(Light::Red, [Light::Green])->isErr());
+    }
+
+    #[Test]
+    public function groupBy_groups_items_preserving_insertion_order(): void
+    {
+        $result = Results::groupBy(
+            ['apple', 'avocado', 'banana', 'apricot'],
+            fn (string $word) => new Ok($word[0]),
+        );
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame([
+            'a' => ['apple', 'avocado', 'apricot'],
+            'b' => ['banana'],
+        ], $result->unwrap());
+    }
+
+    #[Test]
+    public function groupBy_whenKeyFunctionFails_returns_first_err(): void
+    {
+        $result = Results::groupBy(
+            ['apple', '', 'banana', ''],
+            fn (string $word) => $word === '' ? new Err('empty word') : new Ok($word[0]),
+        );
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('empty word', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromEither_right_returns_ok(): void
+    {
+        $result = Results::fromEither(['Right' => 42]);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(42, $result->unwrap());
+    }
+
+    #[Test]
+    public function fromEither_left_returns_err(): void
+    {
+        $result = Results::fromEither(['Left' => 'error']);
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('error', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function fromEither_roundTripsToEither(): void
+    {
+        $this->assertSame(['Right' => 42], Results::fromEither((new Ok(42))->toEither())->toEither());
+        $this->assertSame(['Left' => 'error'], Results::fromEither((new Err('error'))->toEither())->toEither());
+    }
+
+    #[Test]
+    public function fromEither_withoutLeftOrRight_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::fromEither([]); // @phpstan-ignore argument.type
+    }
+
+    #[Test]
+    public function normalize_withResult_returns_it_as_is(): void
+    {
+        $err = new Err('upstream error');
+        $this->assertSame($err, Results::normalize($err, 'missing'));
+    }
+
+    #[Test]
+    public function normalize_withPlainValue_wraps_in_ok(): void
+    {
+        $result = Results::normalize(42, 'missing');
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(42, $result->unwrap());
+    }
+
+    #[Test]
+    public function normalize_withNull_returns_err(): void
+    {
+        $result = Results::normalize(null, 'missing');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('missing', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function race_sequentially_returns_first_operation_result_without_running_the_rest(): void
+    {
+        $called = [];
+        $result = Results::race([
+            function () use (&$called) {
+                $called[] = 'primary';
+
+                return new Err('primary down');
+            },
+            function () use (&$called) {
+                $called[] = 'replica';
+
+                return new Ok('replica data');
+            },
+        ]);
+        $this->assertSame('primary down', $result->unwrapErr());
+        $this->assertSame(['primary'], $called);
+    }
+
+    #[Test]
+    public function race_withNoOperations_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::race([]);
+    }
+
+    #[Test]
+    public function timed_ok_returns_result_and_positive_duration(): void
+    {
+        [$result, $seconds] = Results::timed(function () {
+            usleep(1000);
+
+            return new Ok(42);
+        });
+        $this->assertSame(42, $result->unwrap());
+        $this->assertGreaterThan(0.0, $seconds);
+    }
+
+    #[Test]
+    public function timed_err_returns_result_and_positive_duration(): void
+    {
+        [$result, $seconds] = Results::timed(function () {
+            usleep(1000);
+
+            return new Err('failed');
+        });
+        $this->assertSame('failed', $result->unwrapErr());
+        $this->assertGreaterThan(0.0, $seconds);
+    }
+
+    #[Test]
+    public function timed_whenOperationThrows_wraps_throwable_in_err(): void
+    {
+        $exception = new \RuntimeException('boom');
+        [$result, $seconds] = Results::timed(function () use ($exception): Ok {
+            throw $exception;
+        });
+        $this->assertSame($exception, $result->unwrapErr());
+        $this->assertGreaterThan(0.0, $seconds);
+    }
+
+    #[Test]
+    public function flattenMany_allNestedOk_returns_inner_values(): void
+    {
+        $result = Results::flattenMany(new Ok(new Ok(1)), new Ok(new Ok(2)), new Ok(new Ok(3)));
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame([1, 2, 3], $result->unwrap());
+    }
+
+    #[Test]
+    public function flattenMany_withEmbeddedErr_returns_first_err_at_any_level(): void
+    {
+        $inner = new Err('inner error');
+        $result = Results::flattenMany(new Ok(new Ok(1)), new Ok($inner), new Err('outer error'));
+        $this->assertSame($inner, $result);
+
+        $outer = self::asNestedResult(new Err('outer error'));
+        $this->assertSame($outer, Results::flattenMany(new Ok(new Ok(1)), $outer, new Ok($inner)));
+    }
+
+    #[Test]
+    public function collectFirstN_stops_after_n_values(): void
+    {
+        $consumed = 0;
+        $source = (static function () use (&$consumed): \Generator {
+            foreach ([new Ok(1), new Ok(2), new Ok(3), new Err('never reached')] as $result) {
+                $consumed++;
+                yield $result;
+            }
+        })();
+        $result = Results::collectFirstN($source, 2);
+        $this->assertSame([1, 2], $result->unwrap());
+        $this->assertSame(2, $consumed);
+    }
+
+    #[Test]
+    public function collectFirstN_whenSourceEndsEarly_returns_fewer_values(): void
+    {
+        $result = Results::collectFirstN([new Ok(1), new Ok(2)], 5);
+        $this->assertSame([1, 2], $result->unwrap());
+    }
+
+    #[Test]
+    public function collectFirstN_withErrBeforeN_returns_err_even_after_collecting_some(): void
+    {
+        $err = new Err('page 2 failed');
+        $result = Results::collectFirstN([new Ok(1), $err, new Ok(3)], 3);
+        $this->assertSame($err, $result);
+    }
+
+    #[Test]
+    public function collectFirstN_withNegativeN_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::collectFirstN([new Ok(1)], -1);
+    }
+
+    #[Test]
+    public function run_sends_unwrapped_values_and_wraps_return_value_in_ok(): void
+    {
+        $generator = (static function (): \Generator {
+            /** @var int $width */
+            $width = yield new Ok(2);
+            /** @var int $height */
+            $height = yield new Ok(3);
+
+            return $width * $height;
+        })();
+        $result = Results::run($generator);
+        $this->assertInstanceOf(Ok::class, $result);
+        $this->assertSame(6, $result->unwrap());
+    }
+
+    #[Test]
+    public function run_shortCircuits_on_yielded_err(): void
+    {
+        $resumed = false;
+        $err = new Err('parse failed');
+        $generator = (static function () use ($err, &$resumed): \Generator {
+            yield $err;
+            $resumed = true;
+
+            return 'never';
+        })();
+        $result = Results::run($generator);
+        $this->assertSame($err, $result);
+        $this->assertFalse($resumed);
+    }
+
+    #[Test]
+    public function run_whenGeneratorYieldsNonResult_throws(): void
+    {
+        $generator = (static function (): \Generator {
+            yield 42;
+        })();
+        $this->expectException(\UnexpectedValueException::class);
+        Results::run($generator);
+    }
+
+    #[Test]
+    public function batch_allOk_returns_values_in_order(): void
+    {
+        $result = Results::batch([1, 2, 3], fn (int $id) => new Ok("user $id"));
+        $this->assertSame(['user 1', 'user 2', 'user 3'], $result->unwrap());
+    }
+
+    #[Test]
+    public function batch_failFast_returns_first_err_and_skips_remaining_items(): void
+    {
+        $processed = [];
+        $result = Results::batch([1, 2, 3, 4], function (int $id) use (&$processed) {
+            $processed[] = $id;
+
+            return $id % 2 === 0 ? new Err("item $id failed") : new Ok($id);
+        });
+        $this->assertSame('item 2 failed', $result->unwrapErr());
+        $this->assertSame([1, 2], $processed);
+    }
+
+    #[Test]
+    public function batch_accumulate_returns_all_errors(): void
+    {
+        $processed = [];
+        $result = Results::batch([1, 2, 3, 4], function (int $id) use (&$processed) {
+            $processed[] = $id;
+
+            return $id % 2 === 0 ? new Err("item $id failed") : new Ok($id);
+        }, accumulate: true);
+        $this->assertSame(['item 2 failed', 'item 4 failed'], $result->unwrapErr());
+        $this->assertSame([1, 2, 3, 4], $processed);
+    }
+
+    #[Test]
+    public function reduce_allOk_folds_values(): void
+    {
+        $result = Results::reduce([new Ok(1), new Ok(2), new Ok(3)], fn (int $acc, int $next) => $acc + $next);
+        $this->assertSame(6, $result->unwrap());
+    }
+
+    #[Test]
+    public function reduce_withErr_returns_first_err_without_combining_further(): void
+    {
+        $calls = 0;
+        $err = new Err('second failed');
+        $result = Results::reduce([new Ok(1), $err, new Ok(3)], function (int $acc, int $next) use (&$calls) {
+            $calls++;
+
+            return $acc + $next;
+        });
+        $this->assertSame($err, $result);
+        $this->assertSame(0, $calls);
+    }
+
+    #[Test]
+    public function reduce_withEmptyInput_throws_by_default(): void
+    {
+        /** @var list<Result<int, string>> $results */
+        $results = [];
+        $this->expectException(\InvalidArgumentException::class);
+        Results::reduce($results, fn (int $acc, int $next) => $acc + $next);
+    }
+
+    #[Test]
+    public function reduce_withEmptyInputAndOnEmpty_returns_err(): void
+    {
+        /** @var list<Result<int, string>> $results */
+        $results = [];
+        $result = Results::reduce($results, fn (int $acc, int $next) => $acc + $next, fn () => 'nothing to reduce');
+        $this->assertSame('nothing to reduce', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function flatten_withDepth_collapses_up_to_depth_levels(): void
+    {
+        $innermost = new Ok(1);
+        $middle = new Ok($innermost);
+        $nested = new Ok(new Ok($middle));
+
+        $this->assertSame($middle, Results::flatten($nested, 1));
+        $this->assertSame($innermost, Results::flatten($nested, 2));
+        $this->assertSame($innermost, Results::flatten($nested, \PHP_INT_MAX));
+        $this->assertSame($nested, Results::flatten($nested, 0));
+    }
+
+    #[Test]
+    public function flatten_withDepth_stops_early_at_err(): void
+    {
+        $inner = new Err('inner error');
+        $this->assertSame($inner, Results::flatten(new Ok(new Ok($inner)), \PHP_INT_MAX));
+    }
+
+    #[Test]
+    public function flatten_withNegativeDepth_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::flatten(new Ok(new Ok(1)), -1);
+    }
+
+    #[Test]
+    public function compareValues_withComparableValues_returns_ordering(): void
+    {
+        $this->assertSame(-1, Results::compareValues(1, 2.5, 'incomparable')->unwrap());
+        $this->assertSame(0, Results::compareValues('abc', 'abc', 'incomparable')->unwrap());
+        $this->assertSame(1, Results::compareValues(true, false, 'incomparable')->unwrap());
+    }
+
+    #[Test]
+    public function compareValues_withIncomparableValues_returns_err(): void
+    {
+        foreach ([[1, '1'], [null, 0], [[1], [1]], [new \stdClass(), new \stdClass()], [\NAN, 1.0]] as [$a, $b]) {
+            $result = Results::compareValues($a, $b, 'incomparable');
+            $this->assertTrue($result->isErr());
+            $this->assertSame('incomparable', $result->unwrapErr());
+        }
+    }
+
+    #[Test]
+    public function dig_withExistingPath_returns_ok_with_value(): void
+    {
+        $data = ['users' => [['name' => 'alice'], ['name' => null]]];
+        $this->assertSame('alice', Results::dig($data, ['users', 0, 'name'], 'missing')->unwrap());
+        $this->assertNull(Results::dig($data, ['users', 1, 'name'], 'missing')->unwrap());
+    }
+
+    #[Test]
+    public function dig_withMissingKey_returns_err(): void
+    {
+        $data = ['users' => [['name' => 'alice']]];
+        $result = Results::dig($data, ['users', 1, 'name'], 'missing');
+        $this->assertInstanceOf(Err::class, $result);
+        $this->assertSame('missing', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function dig_withPathThroughScalar_returns_err(): void
+    {
+        $data = ['users' => 'not a list'];
+        $this->assertSame('missing', Results::dig($data, ['users', 0], 'missing')->unwrapErr());
+    }
+
+    #[Test]
+    public function firstOk_returns_first_ok_without_invoking_later_producers(): void
+    {
+        $invoked = [];
+        $result = Results::firstOk(
+            function () use (&$invoked) {
+                $invoked[] = 'cache';
+
+                return new Err('cache miss');
+            },
+            function () use (&$invoked) {
+                $invoked[] = 'database';
+
+                return new Ok('row');
+            },
+            function () use (&$invoked) {
+                $invoked[] = 'api';
+
+                return new Ok('response');
+            },
+        );
+        $this->assertSame('row', $result->unwrap());
+        $this->assertSame(['cache', 'database'], $invoked);
+    }
+
+    #[Test]
+    public function firstOk_allErr_returns_err_with_all_errors(): void
+    {
+        $result = Results::firstOk(fn () => new Err('cache miss'), fn () => new Err('db down'));
+        $this->assertSame(['cache miss', 'db down'], $result->unwrapErr());
+    }
+
+    #[Test]
+    public function mapAll_allOk_calls_function_with_values_in_order(): void
+    {
+        $result = Results::mapAll(
+            [new Ok('a'), new Ok('b'), new Ok('c'), new Ok('d')],
+            fn (string ...$parts) => implode('-', $parts),
+        );
+        $this->assertSame('a-b-c-d', $result->unwrap());
+    }
+
+    #[Test]
+    public function mapAll_ignoresKeysForPositionalArguments(): void
+    {
+        $result = Results::mapAll(['y' => new Ok(3), 'x' => new Ok(4)], fn (int $x, int $y) => "$x,$y");
+        $this->assertSame('3,4', $result->unwrap());
+    }
+
+    #[Test]
+    public function mapAll_withErrInTheMiddle_returns_first_err_without_calling_function(): void
+    {
+        $called = false;
+        $err = new Err('third failed');
+        $result = Results::mapAll(
+            [new Ok(1), new Ok(2), $err, new Ok(4)],
+            function (int ...$values) use (&$called) {
+                $called = true;
+
+                return array_sum($values);
+            },
+        );
+        $this->assertSame($err, $result);
+        $this->assertFalse($called);
+    }
+
+    #[Test]
+    public function indexBy_indexes_items_by_computed_key(): void
+    {
+        $result = Results::indexBy(
+            [['id' => 1, 'name' => 'alice'], ['id' => 2, 'name' => 'bob']],
+            fn (array $user) => new Ok($user['id']),
+        );
+        $this->assertSame([
+            1 => ['id' => 1, 'name' => 'alice'],
+            2 => ['id' => 2, 'name' => 'bob'],
+        ], $result->unwrap());
+    }
+
+    #[Test]
+    public function indexBy_withDuplicateKeys_lastWinsByDefault(): void
+    {
+        $result = Results::indexBy(['apple', 'avocado', 'banana'], fn (string $word) => new Ok($word[0]));
+        $this->assertSame(['a' => 'avocado', 'b' => 'banana'], $result->unwrap());
+    }
+
+    #[Test]
+    public function indexBy_withDuplicateKeys_andErrorOnDuplicate_returns_err(): void
+    {
+        $result = Results::indexBy(['apple', 'avocado', 'banana'], fn (string $word) => new Ok($word[0]), errorOnDuplicate: true);
+        $error = $result->unwrapErr();
+        $this->assertInstanceOf(\UnexpectedValueException::class, $error);
+        $this->assertSame('duplicate key "a"', $error->getMessage());
+    }
+
+    #[Test]
+    public function indexBy_whenKeyFunctionFails_returns_first_err(): void
+    {
+        $result = Results::indexBy(['apple', ''], fn (string $word) => $word === '' ? new Err('empty word') : new Ok($word[0]));
+        $this->assertSame('empty word', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function collectAllSettled_describes_every_result_preserving_order_and_keys(): void
+    {
+        $settled = Results::collectAllSettled([
+            'first' => new Ok(1),
+            'second' => new Err('failed'),
+            'third' => new Ok(3),
+            'fourth' => new Err('also failed'),
+        ]);
+        $this->assertSame([
+            'first' => ['ok' => true, 'value' => 1],
+            'second' => ['ok' => false, 'error' => 'failed'],
+            'third' => ['ok' => true, 'value' => 3],
+            'fourth' => ['ok' => false, 'error' => 'also failed'],
+        ], $settled);
+    }
+
+    #[Test]
+    #[RequiresPhpExtension('pdo_sqlite')]
+    public function fromPdo_whenStatementSucceeds_returns_ok_with_rows(): void
+    {
+        $stmt = self::sqliteStatement(\PDO::ERRMODE_EXCEPTION, 'SELECT id, name FROM users WHERE id > ? ORDER BY id');
+        $result = Results::fromPdo($stmt, [0]);
+        $this->assertSame([['id' => 1, 'name' => 'alice'], ['id' => 2, 'name' => 'bob']], $result->unwrap());
+    }
+
+    #[Test]
+    #[RequiresPhpExtension('pdo_sqlite')]
+    public function fromPdo_inExceptionMode_returns_err_with_pdo_exception(): void
+    {
+        $stmt = self::sqliteStatement(\PDO::ERRMODE_EXCEPTION, 'INSERT INTO users (name) VALUES (?)');
+        $result = Results::fromPdo($stmt, [null]);
+        $this->assertInstanceOf(\PDOException::class, $result->unwrapErr());
+    }
+
+    #[Test]
+    #[RequiresPhpExtension('pdo_sqlite')]
+    public function fromPdo_inSilentMode_returns_err_with_error_info(): void
+    {
+        $stmt = self::sqliteStatement(\PDO::ERRMODE_SILENT, 'INSERT INTO users (name) VALUES (?)');
+        $error = Results::fromPdo($stmt, [null])->unwrapErr();
+        $this->assertIsArray($error);
+        $this->assertSame('23000', $error[0]);
+    }
+
+    /**
+     * Prepares a statement against an in-memory SQLite users table with two rows.
+     */
+    private static function sqliteStatement(int $errorMode, string $sql): \PDOStatement
+    {
+        $pdo = new \PDO('sqlite::memory:', null, null, [
+            \PDO::ATTR_ERRMODE => $errorMode,
+            \PDO::ATTR_DEFAULT_FETCH_MODE => \PDO::FETCH_ASSOC,
+        ]);
+        $pdo->exec('CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)');
+        $pdo->exec("INSERT INTO users (name) VALUES ('alice'), ('bob')");
+        $stmt = $pdo->prepare($sql);
+        self::assertInstanceOf(\PDOStatement::class, $stmt);
+
+        return $stmt;
+    }
+
+    #[Test]
+    public function memoizeBy_caches_results_per_derived_key(): void
+    {
+        $calls = 0;
+        $find = Results::memoizeBy(
+            function (int $id, string $locale) use (&$calls): Result {
+                ++$calls;
+
+                return $id > 0 ? new Ok("{$id}:{$locale}") : new Err('invalid id');
+            },
+            fn (int $id, string $locale): string => "{$id}|{$locale}",
+        );
+
+        $this->assertSame('1:en', $find(1, 'en')->unwrap());
+        $this->assertSame('1:ja', $find(1, 'ja')->unwrap());
+        $this->assertSame('1:en', $find(1, 'en')->unwrap());
+        $this->assertSame('invalid id', $find(0, 'en')->unwrapErr());
+        $this->assertSame('invalid id', $find(0, 'en')->unwrapErr());
+        $this->assertSame(3, $calls);
+    }
+
+    #[Test]
+    public function memoizeBy_keeps_a_separate_cache_per_wrapper(): void
+    {
+        $calls = 0;
+        $op = function (int $n) use (&$calls): Result {
+            ++$calls;
+
+            return new Ok($n * 2);
+        };
+        $keyFn = fn (int $n): int => $n;
+
+        Results::memoizeBy($op, $keyFn)(1);
+        Results::memoizeBy($op, $keyFn)(1);
+        $this->assertSame(2, $calls);
+    }
+
+    #[Test]
+    public function fromEnv_distinguishes_unset_from_empty(): void
+    {
+        putenv('VALBEAT_RESULT_TEST_SET=value');
+        putenv('VALBEAT_RESULT_TEST_EMPTY=');
+        putenv('VALBEAT_RESULT_TEST_UNSET');
+        try {
+            $this->assertSame('value', Results::fromEnv('VALBEAT_RESULT_TEST_SET')->unwrap());
+            $this->assertSame('', Results::fromEnv('VALBEAT_RESULT_TEST_EMPTY')->unwrap());
+            $this->assertSame(
+                'environment variable "VALBEAT_RESULT_TEST_UNSET" is not set',
+                Results::fromEnv('VALBEAT_RESULT_TEST_UNSET')->unwrapErr(),
+            );
+            $this->assertSame('missing', Results::fromEnv('VALBEAT_RESULT_TEST_UNSET', 'missing')->unwrapErr());
+        } finally {
+            putenv('VALBEAT_RESULT_TEST_SET');
+            putenv('VALBEAT_RESULT_TEST_EMPTY');
+        }
+    }
+
+    #[Test]
+    public function fromEnvRequired_rejects_empty_values(): void
+    {
+        putenv('VALBEAT_RESULT_TEST_SET=value');
+        putenv('VALBEAT_RESULT_TEST_EMPTY=');
+        putenv('VALBEAT_RESULT_TEST_UNSET');
+        try {
+            $this->assertSame('value', Results::fromEnvRequired('VALBEAT_RESULT_TEST_SET')->unwrap());
+            $this->assertSame(
+                'environment variable "VALBEAT_RESULT_TEST_EMPTY" is empty',
+                Results::fromEnvRequired('VALBEAT_RESULT_TEST_EMPTY')->unwrapErr(),
+            );
+            $this->assertSame(
+                'environment variable "VALBEAT_RESULT_TEST_UNSET" is not set',
+                Results::fromEnvRequired('VALBEAT_RESULT_TEST_UNSET')->unwrapErr(),
+            );
+            $this->assertSame('required', Results::fromEnvRequired('VALBEAT_RESULT_TEST_EMPTY', 'required')->unwrapErr());
+        } finally {
+            putenv('VALBEAT_RESULT_TEST_SET');
+            putenv('VALBEAT_RESULT_TEST_EMPTY');
+        }
+    }
+
+    #[Test]
+    public function collectMap_whenAllSucceed_returns_ok_with_lookup_table(): void
+    {
+        $result = Results::collectMap(['a=1', 'b=2'], function (string $line): Result {
+            [$key, $value] = explode('=', $line, 2);
+
+            return new Ok([$key, $value]);
+        });
+        $this->assertSame(['a' => '1', 'b' => '2'], $result->unwrap());
+    }
+
+    #[Test]
+    public function collectMap_whenFnFails_returns_first_err_and_stops(): void
+    {
+        $seen = [];
+        $result = Results::collectMap(
+            ['a=1', 'broken', 'c=3'],
+            function (string $line) use (&$seen): Result {
+                $seen[] = $line;
+
+                if (!str_contains($line, '=')) {
+                    return new Err("invalid line: {$line}");
+                }
+                [$key, $value] = explode('=', $line, 2);
+
+                return new Ok([$key, $value]);
+            },
+        );
+        $this->assertSame('invalid line: broken', $result->unwrapErr());
+        $this->assertSame(['a=1', 'broken'], $seen);
+    }
+
+    #[Test]
+    public function collectMap_whenOkIsNotAPair_throws(): void
+    {
+        $this->expectException(\UnexpectedValueException::class);
+        Results::collectMap([1], fn (int $x): Result => new Ok([$x])); // @phpstan-ignore argument.type
+    }
+
+    #[Test]
+    public function splitGenerators_demultiplexes_values_and_errors(): void
+    {
+        [$oks, $errs] = Results::splitGenerators([new Ok(1), new Err('a'), new Ok(2), new Err('b'), new Ok(3)]);
+        $this->assertSame([1, 2, 3], iterator_to_array($oks, false));
+        $this->assertSame(['a', 'b'], iterator_to_array($errs, false));
+    }
+
+    #[Test]
+    public function splitGenerators_reads_the_source_once_and_lazily(): void
+    {
+        $produced = 0;
+        $source = (static function () use (&$produced): \Generator {
+            foreach ([new Err('a'), new Ok(1), new Err('b'), new Ok(2)] as $result) {
+                $produced++;
+                yield $result;
+            }
+        })();
+        [$oks, $errs] = Results::splitGenerators($source);
+        $this->assertSame(0, $produced);
+
+        $this->assertSame(1, $oks->current());
+        $this->assertSame(2, $produced);
+        $this->assertSame('a', $errs->current());
+        $this->assertSame(2, $produced);
+
+        $errs->next();
+        $this->assertSame('b', $errs->current());
+        $this->assertSame(3, $produced);
+        $oks->next();
+        $this->assertSame(2, $oks->current());
+        $this->assertSame(4, $produced);
+    }
+
+    #[Test]
+    public function coalesce_returns_first_ok(): void
+    {
+        $first = new Ok(2);
+        $this->assertSame($first, Results::coalesce(new Err('a'), $first, new Err('b'), new Ok(3)));
+    }
+
+    #[Test]
+    public function coalesce_whenAllErr_returns_last_err(): void
+    {
+        $last = new Err('c');
+        $this->assertSame($last, Results::coalesce(new Err('a'), new Err('b'), $last));
+    }
+
+    #[Test]
+    public function coalesce_withoutResults_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::coalesce();
+    }
+
+    #[Test]
+    public function collectOkOnly_keeps_values_and_drops_errors(): void
+    {
+        $this->assertSame([1, 3], Results::collectOkOnly([new Ok(1), new Err('a'), new Ok(3)]));
+    }
+
+    #[Test]
+    public function collectOkOnly_calls_onDropped_for_each_error(): void
+    {
+        $dropped = [];
+        $values = Results::collectOkOnly(
+            [new Err('a'), new Ok(2), new Err('b')],
+            function (string $error) use (&$dropped): void {
+                $dropped[] = $error;
+            },
+        );
+        $this->assertSame([2], $values);
+        $this->assertSame(['a', 'b'], $dropped);
+    }
+
+    #[Test]
+    public function collectTry_whenGeneratorThrows_returns_err_with_throwable(): void
+    {
+        $exception = new \RuntimeException('connection lost');
+        $results = (static function () use ($exception): \Generator {
+            yield new Ok(1);
+
+            throw $exception;
+        })();
+        $this->assertSame($exception, Results::collectTry($results)->unwrapErr());
+    }
+
+    #[Test]
+    public function collectTry_behaves_like_combine_otherwise(): void
+    {
+        $this->assertSame([1, 2], Results::collectTry([new Ok(1), new Ok(2)])->unwrap());
+        $this->assertSame('bad', Results::collectTry([new Ok(1), new Err('bad')])->unwrapErr());
+    }
+
+    #[Test]
+    public function collectIndexed_reports_index_of_first_failure(): void
+    {
+        $rows = (static function (): \Generator {
+            yield 'a' => new Ok(1);
+            yield 'b' => new Ok(2);
+            yield 'c' => new Err('invalid row');
+            yield 'd' => new Err('also invalid');
+        })();
+        $this->assertSame(['index' => 2, 'error' => 'invalid row'], Results::collectIndexed($rows)->unwrapErr());
+    }
+
+    #[Test]
+    public function collectIndexed_whenAllOk_returns_ok_with_values(): void
+    {
+        $this->assertSame([1, 2], Results::collectIndexed([new Ok(1), new Ok(2)])->unwrap());
+    }
+
+    #[Test]
+    public function okWhen_returns_ok_or_err_depending_on_predicate(): void
+    {
+        $isAdult = fn (int $age): bool => $age >= 18;
+        $this->assertSame(20, Results::okWhen(20, $isAdult, 'too young')->unwrap());
+        $this->assertSame('too young', Results::okWhen(15, $isAdult, 'too young')->unwrapErr());
+    }
+
+    #[Test]
+    public function okWhenWith_computes_error_only_when_predicate_fails(): void
+    {
+        $calls = 0;
+        $errFn = function (int $age) use (&$calls): string {
+            ++$calls;
+
+            return "{$age} is too young";
+        };
+        $isAdult = fn (int $age): bool => $age >= 18;
+        $this->assertSame(20, Results::okWhenWith(20, $isAdult, $errFn)->unwrap());
+        $this->assertSame(0, $calls);
+        $this->assertSame('15 is too young', Results::okWhenWith(15, $isAdult, $errFn)->unwrapErr());
+        $this->assertSame(1, $calls);
+    }
+
+    #[Test]
+    public function fromCount_treats_zero_as_failure(): void
+    {
+        $this->assertSame('no rows affected', Results::fromCount(0, 'no rows affected')->unwrapErr());
+        $this->assertSame(3, Results::fromCount(3, 'no rows affected')->unwrap());
+    }
+
+    #[Test]
+    public function fromCount_withCustomMinimum_requires_at_least_that_many(): void
+    {
+        $this->assertSame('too few', Results::fromCount(1, 'too few', minimum: 2)->unwrapErr());
+        $this->assertSame(2, Results::fromCount(2, 'too few', minimum: 2)->unwrap());
+    }
+
+    #[Test]
+    public function foldErrors_reduces_errors_into_summary(): void
+    {
+        $result = Results::foldErrors(
+            [new Ok(1), new Err('name is required'), new Ok(3), new Err('age is invalid')],
+            'validation failed',
+            fn (string $summary, string $error): string => "{$summary}; {$error}",
+        );
+        $this->assertSame('validation failed; name is required; age is invalid', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function foldErrors_withoutErrors_returns_ok_with_values(): void
+    {
+        $called = false;
+        $result = Results::foldErrors([new Ok(1), new Ok(2)], '', function (string $summary) use (&$called): string {
+            $called = true;
+
+            return $summary;
+        });
+        $this->assertSame([1, 2], $result->unwrap());
+        $this->assertFalse($called);
+    }
+
+    #[Test]
+    public function validateAll_collects_every_failure(): void
+    {
+        $result = Results::validateAll(
+            'ab',
+            fn (string $s): Result => \strlen($s) >= 3 ? new Ok($s) : new Err('too short'),
+            fn (string $s): Result => ctype_lower($s) ? new Ok($s) : new Err('not lowercase'),
+            fn (string $s): Result => ctype_digit($s) ? new Ok($s) : new Err('not numeric'),
+        );
+        $this->assertSame(['too short', 'not numeric'], $result->unwrapErr());
+    }
+
+    #[Test]
+    public function validateAll_whenAllPass_returns_ok_with_value(): void
+    {
+        $result = Results::validateAll(
+            'abc',
+            fn (string $s): Result => \strlen($s) >= 3 ? new Ok(null) : new Err('too short'),
+            fn (string $s): Result => ctype_lower($s) ? new Ok(null) : new Err('not lowercase'),
+        );
+        $this->assertSame('abc', $result->unwrap());
+    }
+
+    #[Test]
+    public function onlyIfChanged_runs_op_only_when_value_changed(): void
+    {
+        $saved = [];
+        $save = function (array $settings) use (&$saved): Result {
+            $saved[] = $settings;
+
+            return new Ok(\count($saved));
+        };
+        $this->assertSame(1, Results::onlyIfChanged(['theme' => 'light'], ['theme' => 'dark'], $save, 'no change')->unwrap());
+        $this->assertSame('no change', Results::onlyIfChanged(['theme' => 'dark'], ['theme' => 'dark'], $save, 'no change')->unwrapErr());
+        $this->assertSame([['theme' => 'dark']], $saved);
+    }
+
+    #[Test]
+    public function onlyIfChanged_withCustomEquality(): void
+    {
+        $equals = fn (\DateTimeImmutable $a, \DateTimeImmutable $b): bool => $a == $b;
+        $op = fn (\DateTimeImmutable $date): Result => new Ok($date->format('Y-m-d'));
+        $result = Results::onlyIfChanged(new \DateTimeImmutable('2024-01-01'), new \DateTimeImmutable('2024-01-01'), $op, 'no change', $equals);
+        $this->assertSame('no change', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function toNdjson_writes_one_tagged_line_per_result(): void
+    {
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        $result = Results::toNdjson([new Ok(['id' => 1]), new Err('not found'), new Ok(null)], $stream);
+        rewind($stream);
+        $this->assertSame(3, $result->unwrap());
+        $this->assertSame("{\"ok\":{\"id\":1}}\n{\"err\":\"not found\"}\n{\"ok\":null}\n", stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function toNdjson_whenPayloadCannotBeEncoded_returns_err(): void
+    {
+        $stream = fopen('php://memory', 'w+');
+        $this->assertIsResource($stream);
+        $result = Results::toNdjson([new Ok(1), new Ok(\NAN)], $stream);
+        rewind($stream);
+        $this->assertInstanceOf(\JsonException::class, $result->unwrapErr());
+        $this->assertSame("{\"ok\":1}\n", stream_get_contents($stream));
+    }
+
+    #[Test]
+    public function retryUntil_stops_when_predicate_accepts(): void
+    {
+        $attempts = [];
+        $result = Results::retryUntil(
+            function (int $attempt) use (&$attempts): Result {
+                $attempts[] = $attempt;
+
+                return new Ok($attempt === 1 ? 'pending' : 'done');
+            },
+            fn (Result $result): bool => $result->isOk() && $result->unwrap() === 'done',
+            5,
+        );
+        $this->assertSame('done', $result->unwrap());
+        $this->assertSame([1, 2], $attempts);
+    }
+
+    #[Test]
+    public function retryUntil_returns_last_result_at_max_attempts(): void
+    {
+        $calls = 0;
+        $result = Results::retryUntil(
+            function (int $attempt) use (&$calls): Result {
+                ++$calls;
+
+                return new Ok("pending {$attempt}");
+            },
+            fn (): bool => false,
+            3,
+        );
+        $this->assertSame('pending 3', $result->unwrap());
+        $this->assertSame(3, $calls);
+    }
+
+    #[Test]
+    public function retryUntil_withZeroMaxAttempts_throws(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        Results::retryUntil(fn (): Result => new Ok(1), fn (): bool => true, 0);
+    }
+
+    #[Test]
+    public function span_onOk_tags_ok_and_finishes_span(): void
+    {
+        $tracer = new RecordingTracer();
+        $result = Results::span('load user', fn (): Result => new Ok(1), $tracer);
+        $this->assertSame(1, $result->unwrap());
+        $this->assertSame(['start load user', 'tag result=ok', 'finish'], $tracer->events);
+    }
+
+    #[Test]
+    public function span_onErr_tags_err_and_records_error_message(): void
+    {
+        $tracer = new RecordingTracer();
+        $result = Results::span('load user', fn (): Result => new Err(new \RuntimeException('not found')), $tracer);
+        $this->assertTrue($result->isErr());
+        $this->assertSame(['start load user', 'tag result=err', 'error not found', 'finish'], $tracer->events);
+    }
+
+    #[Test]
+    public function span_whenOpThrows_records_error_finishes_and_rethrows(): void
+    {
+        $tracer = new RecordingTracer();
+        try {
+            Results::span('load user', function (): Result {
+                throw new \LogicException('bug');
+            }, $tracer);
+            $this->fail('the exception should be rethrown');
+        } catch (\LogicException $e) {
+            $this->assertSame('bug', $e->getMessage());
+        }
+        $this->assertSame(['start load user', 'tag result=err', 'error bug', 'finish'], $tracer->events);
+    }
+
+    #[Test]
+    public function catchAs_maps_exception_types_to_different_errors(): void
+    {
+        $map = [
+            \InvalidArgumentException::class => fn (\Throwable $e): string => "invalid: {$e->getMessage()}",
+            \RuntimeException::class => fn (\Throwable $e): string => "runtime: {$e->getMessage()}",
+        ];
+        $invalid = Results::catchAs(function (): int {
+            throw new \InvalidArgumentException('bad id');
+        }, $map);
+        $runtime = Results::catchAs(function (): int {
+            throw new \RuntimeException('db down');
+        }, $map);
+        $this->assertSame('invalid: bad id', $invalid->unwrapErr());
+        $this->assertSame('runtime: db down', $runtime->unwrapErr());
+        $this->assertSame(1, Results::catchAs(fn (): int => 1, $map)->unwrap());
+    }
+
+    #[Test]
+    public function catchAs_prefers_most_specific_class(): void
+    {
+        $map = [
+            \Exception::class => fn (): string => 'generic',
+            \UnexpectedValueException::class => fn (): string => 'unexpected value',
+            \RuntimeException::class => fn (): string => 'runtime',
+        ];
+        $result = Results::catchAs(function (): int {
+            throw new \UnexpectedValueException('boom');
+        }, $map);
+        $this->assertSame('unexpected value', $result->unwrapErr());
+    }
+
+    #[Test]
+    public function catchAs_rethrows_unmatched_exceptions(): void
+    {
+        $this->expectException(\LogicException::class);
+        Results::catchAs(function (): int {
+            throw new \LogicException('bug');
+        }, [\RuntimeException::class => fn (): string => 'runtime']);
+    }
+
+    #[Test]
+    public function takeUntilErr_returns_values_before_first_err(): void
+    {
+        $consumed = 0;
+        $results = (static function () use (&$consumed): \Generator {
+            foreach ([new Ok(1), new Ok(2), new Err('bad'), new Ok(4)] as $result) {
+                $consumed++;
+                yield $result;
+            }
+        })();
+        $this->assertSame([1, 2], Results::takeUntilErr($results));
+        $this->assertSame(3, $consumed);
+    }
+
+    #[Test]
+    public function takeUntilErr_whenAllOk_returns_all_values(): void
+    {
+        $this->assertSame([1, 2, 3], Results::takeUntilErr([new Ok(1), new Ok(2), new Ok(3)]));
+    }
+
     /**
      * Helper for widening a literal type to Result<Result<int, string>, string>.
      *
@@ -464,6 +464,17 @@ function testTryInference(): void
     assertType('Valbeat\Result\Result<int, Throwable>', Results::try(static fn (): int => 42));
 }
 
+/**
+ * Results::fromValidator keeps the validated value on the Ok side and the validator's non-true output on the Err side.
+ */
+function testFromValidatorInference(string $input): void
+{
+    assertType(
+        'Valbeat\Result\Result<string, string>',
+        Results::fromValidator($input, static fn (string $value): true|string => $value !== '' ? true : 'required'),
+    );
+}
+
 /**
  * Results::combine infers Result<list<T>, E> from iterable<Result<T, E>>.
  *
@@ -482,4 +493,115 @@ function testCombineInference(array $results): void
 function testFlattenInference(Result $nested): void
 {
     assertType('Valbeat\Result\Result<int, LogicException|RuntimeException>', Results::flatten($nested));
+    assertType('Valbeat\Result\Result<mixed, mixed>', Results::flatten($nested, 2));
+}
+
+/**
+ * Results::combine with $flatten cannot track the element types of nested Results.
+ *
+ * @param list<Result<int, RuntimeException>> $results
+ */
+function testCombineFlattenInference(array $results): void
+{
+    assertType('Valbeat\Result\Result<list<mixed>, mixed>', Results::combine($results, flatten: true));
+}
+
+/**
+ * Results::mapAll infers the callback's return type on the Ok side and keeps the error type.
+ *
+ * @param list<Result<int, RuntimeException>> $results
+ */
+function testMapAllInference(array $results): void
+{
+    assertType('Valbeat\Result\Result<string, RuntimeException>', Results::mapAll($results, static fn (int ...$values): string => implode(',', $values)));
+}
+
+/**
+ * Results::batch collects the operation's success values, and the errors as a list when accumulating.
+ *
+ * @param list<int> $ids
+ */
+function testBatchInference(array $ids): void
+{
+    assertType('Valbeat\Result\Result<list<string>, LogicException>', Results::batch($ids, findNameById(...)));
+    assertType('Valbeat\Result\Result<list<string>, list<LogicException>>', Results::batch($ids, findNameById(...), accumulate: true));
+}
+
+/**
+ * @return Result<array{string, int}, RuntimeException>
+ */
+function parseEntry(string $line): Result
+{
+    return $line === '' ? new Err(new RuntimeException('empty line')) : new Ok([$line, \strlen($line)]);
+}
+
+/**
+ * Results::collectMap infers the key and value types from the [key, value] pairs.
+ *
+ * @param list<string> $lines
+ */
+function testCollectMapInference(array $lines): void
+{
+    assertType('Valbeat\Result\Result<array<string, int>, RuntimeException>', Results::collectMap($lines, parseEntry(...)));
+}
+
+/**
+ * Results::fromTuple and Results::fromEither infer the success and error types from the shape.
+ *
+ * @param array{int, RuntimeException|null} $tuple
+ * @param array{Right: int}|array{Left: RuntimeException} $either
+ */
+function testFromTupleAndEitherInference(array $tuple, array $either): void
+{
+    assertType('Valbeat\Result\Result<int, RuntimeException>', Results::fromTuple($tuple));
+    assertType('Valbeat\Result\Result<int, RuntimeException>', Results::fromEither($either));
+}
+
+/**
+ * rescueMap always yields an Ok holding either the recovered or the mapped value.
+ *
+ * @param Result<int, RuntimeException> $result
+ */
+function testRescueMapInference(Result $result): void
+{
+    assertType('Valbeat\Result\Ok<float|string>', $result->rescueMap(static fn (RuntimeException $e): float => 0.0, stringify(...)));
+}
+
+/**
+ * expectNonNull adds the given error to the error type; the success type is not narrowed.
+ *
+ * @param Result<int|null, RuntimeException> $result
+ */
+function testExpectNonNullInference(Result $result): void
+{
+    assertType('Valbeat\Result\Result<int|null, LogicException|RuntimeException>', $result->expectNonNull(new LogicException('missing')));
+}
+
+/**
+ * ensureArray keeps the error type and widens the success type to an array.
+ *
+ * @param Result<int, RuntimeException> $result
+ */
+function testEnsureArrayInference(Result $result): void
+{
+    assertType('Valbeat\Result\Result<array, RuntimeException>', $result->ensureArray());
+}
+
+/**
+ * @param Result<int, RuntimeException> $result
+ */
+function testThenInference(Result $result): void
+{
+    // A plain return value is wrapped in Ok, a returned Result is passed through with its error type combined into E|F
+    assertType('Valbeat\Result\Result<string, RuntimeException>', $result->then(stringify(...)));
+    assertType('Valbeat\Result\Result<string, LogicException|RuntimeException>', $result->then(findNameById(...)));
+}
+
+/**
+ * @param Result<int, RuntimeException> $result
+ */
+function testCatchInference(Result $result): void
+{
+    assertType('Valbeat\Result\Result<int|string, never>', $result->catch(static fn (RuntimeException $e): string => $e->getMessage()));
+    assertType('Valbeat\Result\Result<int|string, LogicException>', $result->catch(static fn (RuntimeException $e): Result => findNameById(0)));
 }